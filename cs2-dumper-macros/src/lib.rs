@@ -0,0 +1,289 @@
+//! A macro that includes a dump of cs2-dumper as Rust constants at compile time, for projects that
+//! would rather check in the JSON of a dump than the generated `.rs` files.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use heck::AsSnakeCase;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Literal, TokenStream as TokenStream2};
+
+use quote::quote;
+
+use serde_json::{Map, Value};
+
+use syn::{Ident, ItemMod, LitStr, parse_macro_input};
+
+/// Fills the module it's applied to with the buttons, interfaces, offsets and schemas of a dump.
+///
+/// The path is relative to the directory of the manifest of the crate, and points to a JSON file
+/// with a property for each category, like the one served at `/dump` by `cs2-dumper --serve` (see
+/// `results.schema.json`). The other properties (e.g. `info`) are ignored.
+///
+/// The modules have the same paths as the generated Rust files below `cs2_dumper`:
+///
+/// ```ignore
+/// #[cs2_dumper_macros::include_dump("dump/results.json")]
+/// mod dump {}
+///
+/// let entity_list = client_base + dump::offsets::client_dll::dwEntityList;
+/// let health = pawn + dump::schemas::client_dll::C_BaseEntity::m_iHealth;
+/// ```
+///
+/// The crate is rebuilt when the file changes.
+#[proc_macro_attribute]
+pub fn include_dump(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(attr as LitStr);
+    let module = parse_macro_input!(item as ItemMod);
+
+    match include_dump_impl(&path, module) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn include_dump_impl(path: &LitStr, module: ItemMod) -> syn::Result<TokenStream2> {
+    let ItemMod {
+        attrs,
+        vis,
+        ident,
+        content,
+        ..
+    } = module;
+
+    let Some((_, items)) = content else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "expected a module with a body, e.g. `mod dump {}`",
+        ));
+    };
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let file_path = PathBuf::from(manifest_dir).join(path.value());
+
+    let error = |message: String| syn::Error::new(path.span(), message);
+
+    let data = fs::read_to_string(&file_path)
+        .map_err(|err| error(format!("failed to read {}: {}", file_path.display(), err)))?;
+
+    let dump: Value = serde_json::from_str(&data)
+        .map_err(|err| error(format!("invalid JSON in {}: {}", file_path.display(), err)))?;
+
+    let dump_items = expand_dump(&dump).map_err(error)?;
+
+    // Tracks the file, so that the crate is rebuilt when it changes.
+    let file_path = file_path.to_string_lossy().into_owned();
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis mod #ident {
+            const _: &str = include_str!(#file_path);
+
+            #(#items)*
+
+            #dump_items
+        }
+    })
+}
+
+/// Returns the modules of constants of a dump, or a description of the first invalid entry.
+fn expand_dump(dump: &Value) -> Result<TokenStream2, String> {
+    let dump = as_object(dump, "the dump")?;
+
+    if !["buttons", "interfaces", "offsets", "schemas"]
+        .iter()
+        .any(|category| dump.contains_key(*category))
+    {
+        return Err("the dump has no buttons, interfaces, offsets or schemas".to_string());
+    }
+
+    let mut tokens = TokenStream2::new();
+
+    // The buttons are all in `client.dll`, so their module is left out.
+    if let Some(buttons) = dump.get("buttons") {
+        let constants = as_object(buttons, "buttons")?
+            .iter()
+            .map(|(module_name, buttons)| constants(buttons, &format!("buttons.{}", module_name)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        tokens.extend(quote! {
+            #[allow(non_upper_case_globals, dead_code)]
+            pub mod buttons {
+                #(#constants)*
+            }
+        });
+    }
+
+    for category in ["interfaces", "offsets"] {
+        let Some(modules) = dump.get(category) else {
+            continue;
+        };
+
+        let category_ident = ident(category);
+
+        let modules = as_object(modules, category)?
+            .iter()
+            .map(|(module_name, values)| {
+                let module_ident = ident(&AsSnakeCase(slugify(module_name)).to_string());
+                let constants = constants(values, &format!("{}.{}", category, module_name))?;
+
+                Ok(quote! {
+                    pub mod #module_ident {
+                        #constants
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        tokens.extend(quote! {
+            #[allow(non_upper_case_globals, dead_code)]
+            pub mod #category_ident {
+                #(#modules)*
+            }
+        });
+    }
+
+    if let Some(schemas) = dump.get("schemas") {
+        let modules = as_object(schemas, "schemas")?
+            .iter()
+            .map(|(module_name, module)| {
+                let path = format!("schemas.{}", module_name);
+                let module_ident = ident(&AsSnakeCase(slugify(module_name)).to_string());
+
+                let classes = as_object(
+                    as_object(module, &path)?
+                        .get("classes")
+                        .ok_or_else(|| format!("{} has no classes", path))?,
+                    &format!("{}.classes", path),
+                )?
+                .iter()
+                .map(|(class_name, class)| {
+                    let path = format!("{}.classes.{}", path, class_name);
+                    let class_ident = ident(&slugify(class_name));
+
+                    let fields = as_object(class, &path)?
+                        .get("fields")
+                        .ok_or_else(|| format!("{} has no fields", path))?;
+
+                    let constants = constants(fields, &format!("{}.fields", path))?;
+
+                    Ok(quote! {
+                        pub mod #class_ident {
+                            #constants
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+
+                Ok(quote! {
+                    pub mod #module_ident {
+                        #(#classes)*
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        tokens.extend(quote! {
+            #[allow(non_upper_case_globals, non_snake_case, dead_code)]
+            pub mod schemas {
+                #(#modules)*
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Returns a `usize` constant for each entry of an object of values.
+fn constants(values: &Value, path: &str) -> Result<TokenStream2, String> {
+    let constants = as_object(values, path)?
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .as_u64()
+                .ok_or_else(|| format!("{}.{} isn't an unsigned integer", path, name))?;
+
+            let name = ident(&slugify(name));
+            let value: Literal = format!("{:#X}", value).parse().unwrap();
+
+            Ok(quote! {
+                pub const #name: usize = #value;
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(quote! { #(#constants)* })
+}
+
+fn as_object<'a>(value: &'a Value, path: &str) -> Result<&'a Map<String, Value>, String> {
+    value
+        .as_object()
+        .ok_or_else(|| format!("{} isn't an object", path))
+}
+
+/// Returns `input` with every character that isn't alphanumeric replaced by an underscore, like
+/// the names of the generated files.
+fn slugify(input: &str) -> String {
+    input.replace(|c: char| !c.is_alphanumeric(), "_")
+}
+
+/// Returns an identifier for a name, which is a raw identifier if the name is a keyword.
+fn ident(name: &str) -> Ident {
+    let name = match name {
+        "Self" | "crate" | "self" | "super" => format!("{}_", name),
+        _ if name.starts_with(|c: char| c.is_ascii_digit()) => format!("_{}", name),
+        _ => name.to_string(),
+    };
+
+    syn::parse_str(&name).unwrap_or_else(|_| Ident::new_raw(&name, proc_macro2::Span::call_site()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn expand() {
+        let dump = json!({
+            "buttons": {
+                "client.dll": { "attack": 0x1A2B3C }
+            },
+            "offsets": {
+                "client.dll": { "dwEntityList": 0x24CED50 }
+            },
+            "schemas": {
+                "client.dll": {
+                    "classes": {
+                        "C_BaseEntity": {
+                            "parent": null,
+                            "fields": { "m_iHealth": 0x344, "type": 0x10 }
+                        }
+                    },
+                    "enums": {}
+                }
+            },
+            "info": { "build_number": 14041 }
+        });
+
+        let tokens = expand_dump(&dump).unwrap().to_string();
+
+        for expected in [
+            "pub mod buttons { pub const attack : usize = 0x1A2B3C ; }",
+            "pub mod offsets { pub mod client_dll { pub const dwEntityList : usize = 0x24CED50 ; } }",
+            "pub mod C_BaseEntity { pub const m_iHealth : usize = 0x344 ; pub const r#type : usize = 0x10 ; }",
+        ] {
+            assert!(tokens.contains(expected), "{}", tokens);
+        }
+
+        assert_eq!(
+            expand_dump(&json!({ "offsets": { "client.dll": { "dwEntityList": -1 } } }))
+                .unwrap_err(),
+            "offsets.client.dll.dwEntityList isn't an unsigned integer"
+        );
+        assert!(expand_dump(&json!({ "info": {} })).is_err());
+    }
+}
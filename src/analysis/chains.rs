@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Result, bail};
+
+use log::{debug, warn};
+
+use memflow::prelude::v1::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::Chain;
+
+pub type ChainMap = BTreeMap<String, BTreeMap<String, PointerChain>>;
+
+/// A sequence of offsets that are followed from a module base to reach a value, e.g.
+/// `[[client.dll + 0x18] + 0x40]` is `[0x18, 0x40]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct PointerChain {
+    pub offsets: Vec<usize>,
+}
+
+impl PointerChain {
+    /// Follows the chain from `base`, returning the address of the value it points to.
+    ///
+    /// Every offset but the last one is added to the current address and dereferenced, the last
+    /// one is only added.
+    pub fn resolve(&self, mem: &mut impl MemoryView, base: Address) -> Result<Address> {
+        let Some((last, offsets)) = self.offsets.split_last() else {
+            bail!("empty pointer chain");
+        };
+
+        let mut address = base;
+
+        for offset in offsets {
+            address = mem.read_addr64(address + *offset as umem).data_part()?;
+
+            if address.is_null() {
+                bail!("null pointer at offset {:#X}", offset);
+            }
+        }
+
+        Ok(address + *last as umem)
+    }
+}
+
+/// Collects the user-defined pointer chains and checks that they can be resolved.
+///
+/// Chains that can't be resolved are still included, since they might only be valid while the
+/// player is in a match.
+pub fn pointer_chains<P: Process + MemoryView>(
+    process: &mut P,
+    chains: &[Chain],
+) -> Result<ChainMap> {
+    let mut map = ChainMap::new();
+
+    for chain in chains {
+        let module = process.module_by_name(&chain.module)?;
+
+        let pointer_chain = PointerChain {
+            offsets: chain.offsets.clone(),
+        };
+
+        match pointer_chain.resolve(process, module.base) {
+            Ok(address) => debug!(
+                "resolved \"{}\" to {:#X} ({})",
+                chain.name, address, chain.module
+            ),
+            Err(err) => warn!("failed to resolve pointer chain {}: {}", chain.name, err),
+        }
+
+        map.entry(chain.module.clone())
+            .or_default()
+            .insert(chain.name.clone(), pointer_chain);
+    }
+
+    Ok(map)
+}
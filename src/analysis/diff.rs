@@ -0,0 +1,252 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use super::AnalysisResult;
+
+use crate::output::slugify;
+
+pub type DiffMap = BTreeMap<String, BTreeMap<String, Change>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    Added { new: i64 },
+    Removed { old: i64 },
+    Changed { old: i64, new: i64 },
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DumpDiff {
+    pub buttons: DiffMap,
+    pub interfaces: DiffMap,
+    pub offsets: DiffMap,
+    pub schemas: DiffMap,
+}
+
+impl DumpDiff {
+    pub fn is_empty(&self) -> bool {
+        self.buttons.is_empty()
+            && self.interfaces.is_empty()
+            && self.offsets.is_empty()
+            && self.schemas.is_empty()
+    }
+
+    /// Returns the changes without the added entries, which can't break code that was written
+    /// against the previous result.
+    pub fn breaking(&self) -> DumpDiff {
+        let breaking = |map: &DiffMap| -> DiffMap {
+            map.iter()
+                .filter_map(|(module_name, changes)| {
+                    let changes: BTreeMap<_, _> = changes
+                        .iter()
+                        .filter(|(_, change)| !matches!(change, Change::Added { .. }))
+                        .map(|(name, change)| (name.clone(), *change))
+                        .collect();
+
+                    (!changes.is_empty()).then(|| (module_name.clone(), changes))
+                })
+                .collect()
+        };
+
+        DumpDiff {
+            buttons: breaking(&self.buttons),
+            interfaces: breaking(&self.interfaces),
+            offsets: breaking(&self.offsets),
+            schemas: breaking(&self.schemas),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        [
+            &self.buttons,
+            &self.interfaces,
+            &self.offsets,
+            &self.schemas,
+        ]
+        .iter()
+        .flat_map(|map| map.values())
+        .map(|changes| changes.len())
+        .sum()
+    }
+}
+
+impl AnalysisResult {
+    /// Computes the changes from `other` (the previous result) to `self`.
+    pub fn diff(&self, other: &AnalysisResult) -> DumpDiff {
+        DumpDiff {
+            buttons: diff_modules(&other.flat_buttons(), &self.flat_buttons()),
+            interfaces: diff_modules(&other.flat_interfaces(), &self.flat_interfaces()),
+            offsets: diff_modules(&other.flat_offsets(), &self.flat_offsets()),
+            schemas: diff_modules(&other.flat_schemas(), &self.flat_schemas()),
+        }
+    }
+
+    fn flat_buttons(&self) -> FlatMap<'_> {
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|(name, value)| (name.clone(), *value as i64))
+            .collect();
+
+        // Buttons are always located in `client.dll`.
+        BTreeMap::from([("client.dll", buttons)])
+    }
+
+    fn flat_interfaces(&self) -> FlatMap<'_> {
+        self.interfaces
+            .iter()
+            .map(|(module_name, ifaces)| {
+                let ifaces = ifaces
+                    .iter()
+                    .map(|(name, value)| (name.clone(), *value as i64))
+                    .collect();
+
+                (module_name.as_str(), ifaces)
+            })
+            .collect()
+    }
+
+    fn flat_offsets(&self) -> FlatMap<'_> {
+        self.offsets
+            .iter()
+            .map(|(module_name, offsets)| {
+                let offsets = offsets
+                    .iter()
+                    .map(|(name, value)| (name.clone(), *value as i64))
+                    .collect();
+
+                (module_name.as_str(), offsets)
+            })
+            .collect()
+    }
+
+    fn flat_schemas(&self) -> FlatMap<'_> {
+        self.schemas
+            .iter()
+            .map(|(module_name, (classes, _))| {
+                // Class names are slugified in the JSON output, so compare them the same way.
+                let fields = classes
+                    .iter()
+                    .flat_map(|class| {
+                        class.fields.iter().map(move |field| {
+                            (
+                                format!("{}.{}", slugify(&class.name), field.name),
+                                field.offset as i64,
+                            )
+                        })
+                    })
+                    .collect();
+
+                (module_name.as_str(), fields)
+            })
+            .collect()
+    }
+}
+
+type FlatMap<'a> = BTreeMap<&'a str, BTreeMap<String, i64>>;
+
+fn diff_modules(old: &FlatMap<'_>, new: &FlatMap<'_>) -> DiffMap {
+    let empty = BTreeMap::new();
+
+    let module_names: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+
+    module_names
+        .into_iter()
+        .filter_map(|module_name| {
+            let changes = diff_values(
+                old.get(module_name).unwrap_or(&empty),
+                new.get(module_name).unwrap_or(&empty),
+            );
+
+            (!changes.is_empty()).then(|| (module_name.to_string(), changes))
+        })
+        .collect()
+}
+
+fn diff_values(
+    old: &BTreeMap<String, i64>,
+    new: &BTreeMap<String, i64>,
+) -> BTreeMap<String, Change> {
+    let mut changes = BTreeMap::new();
+
+    for (name, &old_value) in old {
+        match new.get(name) {
+            Some(&new_value) if new_value != old_value => {
+                changes.insert(
+                    name.clone(),
+                    Change::Changed {
+                        old: old_value,
+                        new: new_value,
+                    },
+                );
+            }
+            Some(_) => {}
+            None => {
+                changes.insert(name.clone(), Change::Removed { old: old_value });
+            }
+        }
+    }
+
+    for (name, &new_value) in new {
+        if !old.contains_key(name) {
+            changes.insert(name.clone(), Change::Added { new: new_value });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_offsets() {
+        let old = AnalysisResult {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([
+                    ("dwChanged".to_string(), 0x10),
+                    ("dwRemoved".to_string(), 0x20),
+                    ("dwSame".to_string(), 0x30),
+                ]),
+            )]),
+            ..Default::default()
+        };
+
+        let new = AnalysisResult {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([
+                    ("dwAdded".to_string(), 0x40),
+                    ("dwChanged".to_string(), 0x18),
+                    ("dwSame".to_string(), 0x30),
+                ]),
+            )]),
+            ..Default::default()
+        };
+
+        let diff = new.diff(&old);
+
+        assert_eq!(diff.len(), 3);
+
+        let changes = &diff.offsets["client.dll"];
+
+        assert_eq!(changes["dwAdded"], Change::Added { new: 0x40 });
+        assert_eq!(
+            changes["dwChanged"],
+            Change::Changed {
+                old: 0x10,
+                new: 0x18
+            }
+        );
+        assert_eq!(changes["dwRemoved"], Change::Removed { old: 0x20 });
+        assert!(!changes.contains_key("dwSame"));
+
+        let breaking = diff.breaking();
+
+        assert_eq!(breaking.len(), 2);
+        assert!(!breaking.offsets["client.dll"].contains_key("dwAdded"));
+    }
+}
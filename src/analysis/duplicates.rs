@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use memflow::prelude::v1::*;
+
+use super::AnalysisResult;
+
+impl AnalysisResult {
+    /// Returns the offset names that are found in multiple modules with different values, together
+    /// with the module and value of each occurrence.
+    pub fn duplicate_offset_names(&self) -> BTreeMap<&str, Vec<(&str, umem)>> {
+        let mut names = BTreeMap::<_, Vec<_>>::new();
+
+        for (module_name, offsets) in &self.offsets {
+            for (name, value) in offsets {
+                names
+                    .entry(name.as_str())
+                    .or_default()
+                    .push((module_name.as_str(), *value));
+            }
+        }
+
+        names.retain(|_, occurrences| {
+            occurrences
+                .iter()
+                .any(|(_, value)| *value != occurrences[0].1)
+        });
+
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_names() {
+        let result = AnalysisResult {
+            offsets: BTreeMap::from([
+                (
+                    "client.dll".to_string(),
+                    BTreeMap::from([
+                        ("dwDifferent".to_string(), 0x10),
+                        ("dwSame".to_string(), 0x20),
+                    ]),
+                ),
+                (
+                    "engine2.dll".to_string(),
+                    BTreeMap::from([
+                        ("dwDifferent".to_string(), 0x18),
+                        ("dwSame".to_string(), 0x20),
+                    ]),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let duplicates = result.duplicate_offset_names();
+
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(
+            duplicates["dwDifferent"],
+            [("client.dll", 0x10), ("engine2.dll", 0x18)]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use anyhow::Result;
+
+use log::info;
+
+use regex::Regex;
+
+use super::AnalysisResult;
+
+use crate::config::DumpConfig;
+
+impl AnalysisResult {
+    /// Returns a copy of the result with the filters of `config` applied, or `None` if no filters
+    /// are set.
+    pub fn filtered(&self, config: &DumpConfig) -> Result<Option<AnalysisResult>> {
+        let module_filter = config.module_filter()?;
+        let offset_filter = config.offset_filter()?;
+        let schema_filter = config.schema_filter()?;
+
+        if module_filter.is_none() && offset_filter.is_none() && schema_filter.is_none() {
+            return Ok(None);
+        }
+
+        let mut result = self.clone();
+
+        if let Some(filter) = &module_filter {
+            info!("filtered out {} modules", result.retain_modules(filter));
+        }
+
+        if let Some(filter) = &offset_filter {
+            info!("filtered out {} offsets", result.retain_offsets(filter));
+        }
+
+        if let Some(filter) = &schema_filter {
+            info!(
+                "filtered out {} classes and enums",
+                result.retain_schemas(filter)
+            );
+        }
+
+        Ok(Some(result))
+    }
+
+    /// Removes all modules whose name doesn't match `filter`, returning the number of removed
+    /// modules.
+    ///
+    /// Buttons are always kept, since they're not grouped by module.
+    pub fn retain_modules(&mut self, filter: &Regex) -> usize {
+        let count = self.module_count();
+
+        self.chains.retain(|name, _| filter.is_match(name));
+        self.interfaces.retain(|name, _| filter.is_match(name));
+        self.offsets.retain(|name, _| filter.is_match(name));
+        self.schemas.retain(|name, _| filter.is_match(name));
+        self.vtables.retain(|name, _| filter.is_match(name));
+
+        count - self.module_count()
+    }
+
+    /// Removes all buttons, pointer chains, interfaces, offsets and vtables whose name doesn't
+    /// match `filter`, returning the number of removed entries.
+    pub fn retain_offsets(&mut self, filter: &Regex) -> usize {
+        let count = self.offset_count();
+
+        self.buttons.retain(|name, _| filter.is_match(name));
+
+        for chains in self.chains.values_mut() {
+            chains.retain(|name, _| filter.is_match(name));
+        }
+
+        for ifaces in self.interfaces.values_mut() {
+            ifaces.retain(|name, _| filter.is_match(name));
+        }
+
+        for offsets in self.offsets.values_mut() {
+            offsets.retain(|name, _| filter.is_match(name));
+        }
+
+        for vtables in self.vtables.values_mut() {
+            vtables.retain(|name, _| filter.is_match(name));
+        }
+
+        count - self.offset_count()
+    }
+
+    /// Removes all schema classes and enums whose name doesn't match `filter`, returning the
+    /// number of removed classes and enums.
+    pub fn retain_schemas(&mut self, filter: &Regex) -> usize {
+        let count = self.schema_count();
+
+        for (classes, enums) in self.schemas.values_mut() {
+            classes.retain(|class| filter.is_match(&class.name));
+            enums.retain(|enum_| filter.is_match(&enum_.name));
+        }
+
+        count - self.schema_count()
+    }
+
+    fn module_count(&self) -> usize {
+        self.chains.len()
+            + self.interfaces.len()
+            + self.offsets.len()
+            + self.schemas.len()
+            + self.vtables.len()
+    }
+
+    fn offset_count(&self) -> usize {
+        self.buttons.len()
+            + self
+                .chains
+                .values()
+                .map(|chains| chains.len())
+                .sum::<usize>()
+            + self
+                .interfaces
+                .values()
+                .map(|ifaces| ifaces.len())
+                .sum::<usize>()
+            + self
+                .offsets
+                .values()
+                .map(|offsets| offsets.len())
+                .sum::<usize>()
+            + self
+                .vtables
+                .values()
+                .map(|vtables| vtables.len())
+                .sum::<usize>()
+    }
+
+    fn schema_count(&self) -> usize {
+        self.schemas
+            .values()
+            .map(|(classes, enums)| classes.len() + enums.len())
+            .sum()
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+
+use memflow::prelude::v1::*;
+
+use super::AnalysisResult;
+
+impl AnalysisResult {
+    /// Returns the offsets of all modules in a single map, keyed by `<module>::<name>` (e.g.
+    /// `client.dll::dwEntityList`).
+    #[inline]
+    pub fn to_flat_map(&self) -> BTreeMap<String, umem> {
+        self.to_flat_map_with_separator("::")
+    }
+
+    /// Returns the offsets of all modules in a single map, keyed by the module name and the
+    /// offset name joined with `separator`.
+    ///
+    /// The keys are only unique if no module or offset name contains the separator. If two keys
+    /// are the same anyway, the offset of the module that comes last by name is kept.
+    pub fn to_flat_map_with_separator(&self, separator: &str) -> BTreeMap<String, umem> {
+        self.offsets
+            .iter()
+            .flat_map(|(module_name, offsets)| {
+                offsets.iter().map(move |(name, value)| {
+                    (format!("{}{}{}", module_name, separator, name), *value)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_map() {
+        let result = AnalysisResult {
+            offsets: BTreeMap::from([
+                (
+                    "client.dll".to_string(),
+                    BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+                ),
+                (
+                    "engine2.dll".to_string(),
+                    BTreeMap::from([("dwEntityList".to_string(), 0x5A1234)]),
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            result.to_flat_map(),
+            BTreeMap::from([
+                ("client.dll::dwEntityList".to_string(), 0x24CED50),
+                ("engine2.dll::dwEntityList".to_string(), 0x5A1234),
+            ])
+        );
+
+        assert!(
+            result
+                .to_flat_map_with_separator(".")
+                .contains_key("client.dll.dwEntityList")
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Result, bail};
+
+use clap::ValueEnum;
+
+use serde::Deserialize;
+
+use super::{AnalysisResult, Class, Enum, VtableEntry};
+
+/// How to resolve entries that have different values in the merged results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Use the value of the newest result.
+    #[default]
+    LatestWins,
+    /// Use the value of the oldest result.
+    OldestWins,
+    /// Fail the merge.
+    ErrorOnConflict,
+}
+
+impl AnalysisResult {
+    /// Merges the entries of all `sources`, which must be ordered from oldest to newest.
+    ///
+    /// The address mode of the newest result is kept.
+    pub fn merge(sources: &[&AnalysisResult], strategy: MergeStrategy) -> Result<AnalysisResult> {
+        let mut result = AnalysisResult::default();
+
+        for source in sources {
+            merge_entries(
+                "buttons",
+                &mut result.buttons,
+                &source.buttons,
+                strategy,
+                |a, b| a == b,
+            )?;
+
+            merge_modules(
+                "chains",
+                &mut result.chains,
+                &source.chains,
+                strategy,
+                |a, b| a == b,
+            )?;
+
+            merge_modules(
+                "interfaces",
+                &mut result.interfaces,
+                &source.interfaces,
+                strategy,
+                |a, b| a == b,
+            )?;
+
+            merge_modules(
+                "offsets",
+                &mut result.offsets,
+                &source.offsets,
+                strategy,
+                |a, b| a == b,
+            )?;
+
+            merge_modules(
+                "vtables",
+                &mut result.vtables,
+                &source.vtables,
+                strategy,
+                |a, b| same_vtable(a, b),
+            )?;
+
+            for (module_name, (classes, enums)) in &source.schemas {
+                let (merged_classes, merged_enums) =
+                    result.schemas.entry(module_name.clone()).or_default();
+
+                let context = format!("schemas.{}", module_name);
+
+                merge_named(
+                    &context,
+                    merged_classes,
+                    classes,
+                    strategy,
+                    |class| &class.name,
+                    same_class,
+                )?;
+
+                merge_named(
+                    &context,
+                    merged_enums,
+                    enums,
+                    strategy,
+                    |enum_| &enum_.name,
+                    same_enum,
+                )?;
+            }
+
+            result.address_mode = source.address_mode;
+        }
+
+        Ok(result)
+    }
+}
+
+fn merge_modules<V: Clone>(
+    category: &str,
+    target: &mut BTreeMap<String, BTreeMap<String, V>>,
+    source: &BTreeMap<String, BTreeMap<String, V>>,
+    strategy: MergeStrategy,
+    eq: impl Fn(&V, &V) -> bool + Copy,
+) -> Result<()> {
+    for (module_name, entries) in source {
+        merge_entries(
+            &format!("{}.{}", category, module_name),
+            target.entry(module_name.clone()).or_default(),
+            entries,
+            strategy,
+            eq,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn merge_entries<V: Clone>(
+    context: &str,
+    target: &mut BTreeMap<String, V>,
+    source: &BTreeMap<String, V>,
+    strategy: MergeStrategy,
+    eq: impl Fn(&V, &V) -> bool,
+) -> Result<()> {
+    for (name, value) in source {
+        match target.get_mut(name) {
+            Some(existing) => {
+                if !eq(existing, value) {
+                    resolve_conflict(context, name, strategy, existing, value)?;
+                }
+            }
+            None => {
+                target.insert(name.clone(), value.clone());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Merges lists that are keyed by the name of their elements, keeping the original order and
+/// appending new elements.
+///
+/// Equal elements are replaced by the newer one, since older dumps read back from JSON files only
+/// contain part of the data.
+fn merge_named<T: Clone>(
+    context: &str,
+    target: &mut Vec<T>,
+    source: &[T],
+    strategy: MergeStrategy,
+    name: impl Fn(&T) -> &String,
+    eq: impl Fn(&T, &T) -> bool,
+) -> Result<()> {
+    for value in source {
+        match target
+            .iter_mut()
+            .find(|existing| name(existing) == name(value))
+        {
+            Some(existing) => {
+                if eq(existing, value) {
+                    *existing = value.clone();
+                } else {
+                    let name = name(value).clone();
+
+                    resolve_conflict(context, &name, strategy, existing, value)?;
+                }
+            }
+            None => target.push(value.clone()),
+        }
+    }
+
+    Ok(())
+}
+
+fn resolve_conflict<V: Clone>(
+    context: &str,
+    name: &str,
+    strategy: MergeStrategy,
+    existing: &mut V,
+    value: &V,
+) -> Result<()> {
+    match strategy {
+        MergeStrategy::LatestWins => *existing = value.clone(),
+        MergeStrategy::OldestWins => {}
+        MergeStrategy::ErrorOnConflict => bail!("conflicting values for {}.{}", context, name),
+    }
+
+    Ok(())
+}
+
+fn same_vtable(a: &[VtableEntry], b: &[VtableEntry]) -> bool {
+    a.len() == b.len()
+        && a.iter()
+            .zip(b)
+            .all(|(a, b)| a.index == b.index && a.address == b.address)
+}
+
+/// Classes are only compared by their field offsets, since older dumps don't contain the type
+/// names and metadata, and list the fields by name rather than by offset.
+fn same_class(a: &Class, b: &Class) -> bool {
+    let field_offsets = |class: &Class| {
+        class
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), field.offset))
+            .collect::<BTreeSet<_>>()
+    };
+
+    field_offsets(a) == field_offsets(b)
+}
+
+fn same_enum(a: &Enum, b: &Enum) -> bool {
+    a.members.len() == b.members.len()
+        && a.members
+            .iter()
+            .zip(&b.members)
+            .all(|(a, b)| a.name == b.name && a.value == b.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::ClassField;
+
+    use super::*;
+
+    fn offsets(entries: &[(&str, u64)]) -> AnalysisResult {
+        AnalysisResult {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                entries
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), *value))
+                    .collect(),
+            )]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn merge_strategies() {
+        let old = offsets(&[("dwOld", 0x10), ("dwShared", 0x20)]);
+        let new = offsets(&[("dwNew", 0x30), ("dwShared", 0x28)]);
+
+        let latest = AnalysisResult::merge(&[&old, &new], MergeStrategy::LatestWins).unwrap();
+        let client = &latest.offsets["client.dll"];
+
+        assert_eq!(client.len(), 3);
+        assert_eq!(client["dwShared"], 0x28);
+
+        let oldest = AnalysisResult::merge(&[&old, &new], MergeStrategy::OldestWins).unwrap();
+
+        assert_eq!(oldest.offsets["client.dll"]["dwShared"], 0x20);
+
+        assert!(AnalysisResult::merge(&[&old, &new], MergeStrategy::ErrorOnConflict).is_err());
+    }
+
+    #[test]
+    fn merge_read_back_classes() {
+        let field = |name: &str, type_name: &str, offset| ClassField {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            offset,
+            alignment: None,
+        };
+
+        let class = |fields| AnalysisResult {
+            schemas: BTreeMap::from([(
+                "client.dll".to_string(),
+                (
+                    vec![Class {
+                        name: "C_BaseEntity".to_string(),
+                        module_name: "client.dll".to_string(),
+                        parent_name: None,
+                        size: 0x10,
+                        alignment: 8,
+                        metadata: Vec::new(),
+                        fields,
+                    }],
+                    Vec::new(),
+                ),
+            )]),
+            ..Default::default()
+        };
+
+        // The fields of a dump that's read back are ordered by name and have no types.
+        let old = class(vec![
+            field("m_iHealth", "", 0x8),
+            field("m_lifeState", "", 0x4),
+        ]);
+        let new = class(vec![
+            field("m_lifeState", "uint8", 0x4),
+            field("m_iHealth", "int32", 0x8),
+        ]);
+
+        for strategy in [MergeStrategy::OldestWins, MergeStrategy::ErrorOnConflict] {
+            let merged = AnalysisResult::merge(&[&old, &new], strategy).unwrap();
+            let fields = &merged.schemas["client.dll"].0[0].fields;
+
+            assert_eq!(fields[0].type_name, "uint8");
+            assert_eq!(fields[1].type_name, "int32");
+        }
+    }
+}
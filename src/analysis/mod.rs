@@ -1,93 +1,254 @@
 pub use buttons::*;
+pub use chains::*;
+pub use diff::*;
 pub use interfaces::*;
+pub use merge::MergeStrategy;
 pub use offsets::*;
+pub use scanner::PatternScanner;
+pub use schema_graph::SchemaGraph;
 pub use schemas::*;
+pub use vtables::*;
 
 use std::any::type_name;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
-use log::{error, info};
+use log::{error, info, warn};
 
 use memflow::prelude::v1::*;
 
+use serde::{Deserialize, Serialize};
+
+use crate::config::DumpConfig;
+use crate::memory::retry::RetryConfig;
+
 mod buttons;
+mod chains;
+mod diff;
+mod duplicates;
+mod filter;
+mod flat_map;
 mod interfaces;
+mod merge;
 mod offsets;
+mod scanner;
+mod schema_graph;
 mod schemas;
+mod summary;
+mod vtables;
+
+/// All item categories that can be analyzed.
+pub const CATEGORIES: &[&str] = &["buttons", "interfaces", "offsets", "schemas", "vtables"];
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct AnalysisResult {
     pub buttons: ButtonMap,
     pub interfaces: InterfaceMap,
     pub offsets: OffsetMap,
+    pub address_mode: AddressMode,
+    pub chains: ChainMap,
     pub schemas: SchemaMap,
+    pub vtables: VtableMap,
 }
 
-pub fn analyze_all<P: Process + MemoryView>(process: &mut P) -> Result<AnalysisResult> {
-    let buttons = analyze(process, buttons);
+/// Analyzes the enabled categories, returning the result and whether every one of them succeeded.
+///
+/// A category that fails is logged and left empty, so that the others are still dumped.
+pub fn analyze_all<P: Process + MemoryView>(
+    process: &mut P,
+    config: &DumpConfig,
+) -> Result<(AnalysisResult, bool)> {
+    let is_enabled = |category: &str| config.categories.iter().any(|c| c == category);
 
-    info!("found {} buttons", buttons.len());
+    let mut result = AnalysisResult::default();
+    let mut complete = true;
 
-    let interfaces = analyze(process, interfaces);
+    if is_enabled("buttons") {
+        result.buttons = analyze(process, &config.retry, &mut complete, buttons);
 
-    info!(
-        "found {} interfaces across {} modules",
-        interfaces
-            .iter()
-            .map(|(_, ifaces)| ifaces.len())
-            .sum::<usize>(),
-        interfaces.len()
-    );
+        info!("found {} buttons", result.buttons.len());
+    }
 
-    let offsets = analyze(process, offsets);
+    if is_enabled("interfaces") {
+        result.interfaces = analyze(process, &config.retry, &mut complete, interfaces);
+
+        info!(
+            "found {} interfaces across {} modules",
+            result
+                .interfaces
+                .iter()
+                .map(|(_, ifaces)| ifaces.len())
+                .sum::<usize>(),
+            result.interfaces.len()
+        );
+    }
 
-    info!(
-        "found {} offsets across {} modules",
-        offsets
-            .iter()
-            .map(|(_, offsets)| offsets.len())
-            .sum::<usize>(),
-        offsets.len()
-    );
+    if is_enabled("offsets") {
+        let mut builder =
+            OffsetMapBuilder::from(analyze(process, &config.retry, &mut complete, offsets));
 
-    let schemas = analyze(process, schemas);
+        if !config.signatures.is_empty() {
+            let custom_offsets = analyze(process, &config.retry, &mut complete, |process| {
+                signature_offsets(process, &config.signatures)
+            });
 
-    let (class_count, enum_count) =
-        schemas
-            .values()
-            .fold((0, 0), |(classes, enums), (class_vec, enum_vec)| {
-                (classes + class_vec.len(), enums + enum_vec.len())
+            for (module_name, offsets) in custom_offsets {
+                for (name, value) in offsets {
+                    // A signature with the same name as a built-in offset replaces it.
+                    let Some(old_value) = builder.insert_offset(&module_name, &name, value) else {
+                        continue;
+                    };
+
+                    if config.strict {
+                        bail!(
+                            "duplicate offset \"{}\" in {} ({:#X} and {:#X})",
+                            name,
+                            module_name,
+                            old_value,
+                            value
+                        );
+                    }
+
+                    warn!(
+                        "duplicate offset \"{}\" in {} ({:#X} and {:#X}), using {:#X}",
+                        name, module_name, old_value, value, value
+                    );
+                }
+            }
+        }
+
+        result.offsets = builder.finish();
+
+        // Consumers often flatten the offsets of all modules into a single map.
+        for (name, occurrences) in result.duplicate_offset_names() {
+            let occurrences = occurrences
+                .iter()
+                .map(|(module_name, value)| format!("{} = {:#X}", module_name, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            if config.strict_unique_names {
+                bail!(
+                    "offset \"{}\" has different values across modules ({})",
+                    name,
+                    occurrences
+                );
+            }
+
+            warn!(
+                "offset \"{}\" has different values across modules ({})",
+                name, occurrences
+            );
+        }
+
+        if config.address_mode == AddressMode::Absolute {
+            // Keep the RVAs if any of the module bases can't be read, rather than mixing both.
+            let mut offsets = result.offsets.clone();
+
+            match to_absolute(process, &mut offsets) {
+                Ok(()) => {
+                    result.offsets = offsets;
+                    result.address_mode = AddressMode::Absolute;
+                }
+                Err(err) => {
+                    error!("failed to convert offsets to absolute addresses: {}", err);
+
+                    complete = false;
+                }
+            }
+        }
+
+        if !config.chains.is_empty() {
+            result.chains = analyze(process, &config.retry, &mut complete, |process| {
+                pointer_chains(process, &config.chains)
             });
 
-    info!(
-        "found {} classes and {} enums across {} modules",
-        class_count,
-        enum_count,
-        schemas.len()
-    );
-
-    Ok(AnalysisResult {
-        buttons,
-        interfaces,
-        offsets,
-        schemas,
-    })
+            info!(
+                "found {} pointer chains",
+                result
+                    .chains
+                    .values()
+                    .map(|chains| chains.len())
+                    .sum::<usize>()
+            );
+        }
+
+        info!(
+            "found {} offsets across {} modules",
+            result
+                .offsets
+                .iter()
+                .map(|(_, offsets)| offsets.len())
+                .sum::<usize>(),
+            result.offsets.len()
+        );
+    }
+
+    if is_enabled("schemas") {
+        result.schemas = analyze(process, &config.retry, &mut complete, schemas);
+
+        let (class_count, enum_count) =
+            result
+                .schemas
+                .values()
+                .fold((0, 0), |(classes, enums), (class_vec, enum_vec)| {
+                    (classes + class_vec.len(), enums + enum_vec.len())
+                });
+
+        info!(
+            "found {} classes and {} enums across {} modules",
+            class_count,
+            enum_count,
+            result.schemas.len()
+        );
+    }
+
+    if is_enabled("vtables") {
+        // The vtables are read through the interface instances.
+        let iface_map;
+
+        let ifaces = if is_enabled("interfaces") {
+            &result.interfaces
+        } else {
+            iface_map = analyze(process, &config.retry, &mut complete, interfaces);
+
+            &iface_map
+        };
+
+        result.vtables = analyze(process, &config.retry, &mut complete, |process| {
+            vtables(process, ifaces, config.vtable_size)
+        });
+
+        info!(
+            "found {} vtables across {} modules",
+            result
+                .vtables
+                .iter()
+                .map(|(_, vtables)| vtables.len())
+                .sum::<usize>(),
+            result.vtables.len()
+        );
+    }
+
+    Ok((result, complete))
 }
 
-fn analyze<P, F, T>(process: &mut P, f: F) -> T
+fn analyze<P, F, T>(process: &mut P, retry: &RetryConfig, complete: &mut bool, mut f: F) -> T
 where
     P: Process + MemoryView,
-    F: FnOnce(&mut P) -> Result<T>,
+    F: FnMut(&mut P) -> Result<T>,
     T: Default,
 {
     let name = type_name::<F>();
 
-    match f(process) {
+    // The reads can fail while the game is loading, so the whole analysis is retried.
+    match retry.retry(|| f(process)) {
         Ok(result) => result,
         Err(err) => {
             error!("failed to read {}: {}", name, err);
 
+            *complete = false;
+
             T::default()
         }
     }
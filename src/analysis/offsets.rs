@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
+
+use clap::ValueEnum;
 
 use log::{debug, error};
 
@@ -12,7 +14,119 @@ use pelite::pe64::{Pe, PeView, Rva};
 
 use phf::{Map, phf_map};
 
-pub type OffsetMap = BTreeMap<String, BTreeMap<String, Rva>>;
+use serde::{Deserialize, Serialize};
+
+use super::PatternScanner;
+
+use crate::config::Signature;
+
+pub type OffsetMap = BTreeMap<String, BTreeMap<String, umem>>;
+
+/// Builds an [`OffsetMap`] one offset at a time, so that duplicates are found as they're inserted.
+#[derive(Debug, Default)]
+pub struct OffsetMapBuilder {
+    map: OffsetMap,
+    len: usize,
+}
+
+impl OffsetMapBuilder {
+    /// Inserts an offset, replacing the offset with the same name in the same module if there is
+    /// one. Returns the value of the replaced offset.
+    pub fn insert_offset(&mut self, module_name: &str, name: &str, value: umem) -> Option<umem> {
+        let old_value = self
+            .map
+            .entry(module_name.to_string())
+            .or_default()
+            .insert(name.to_string(), value);
+
+        if old_value.is_none() {
+            self.len += 1;
+        }
+
+        old_value
+    }
+
+    /// Returns the number of offsets across all modules.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline]
+    pub fn finish(self) -> OffsetMap {
+        self.map
+    }
+}
+
+impl From<OffsetMap> for OffsetMapBuilder {
+    fn from(map: OffsetMap) -> Self {
+        let len = map.values().map(|offsets| offsets.len()).sum();
+
+        Self { map, len }
+    }
+}
+
+/// How the values of an [`OffsetMap`] are interpreted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressMode {
+    /// The values are relative to the base address of their module.
+    #[default]
+    Rva,
+    /// The values are absolute virtual addresses at the time of the dump.
+    Absolute,
+}
+
+/// The type of the value that an offset points to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OffsetType {
+    U8,
+    U16,
+    U32,
+    U64,
+    Ptr,
+    Bool,
+    Float,
+    Double,
+}
+
+/// The value types of the offsets that are known. Offsets that resolve to an object rather than a
+/// pointer to one (e.g. `dwPrediction`) are left out.
+static OFFSET_TYPES: Map<&'static str, OffsetType> = phf_map! {
+    "dwBuildNumber" => OffsetType::U32,
+    "dwCSGOInput" => OffsetType::Ptr,
+    "dwEntityList" => OffsetType::Ptr,
+    "dwGameEntitySystem" => OffsetType::Ptr,
+    "dwGameEntitySystem_highestEntityIndex" => OffsetType::U32,
+    "dwGameRules" => OffsetType::Ptr,
+    "dwGlobalVars" => OffsetType::Ptr,
+    "dwGlowManager" => OffsetType::Ptr,
+    "dwInputSystem" => OffsetType::Ptr,
+    "dwLocalPlayerController" => OffsetType::Ptr,
+    "dwLocalPlayerPawn" => OffsetType::Ptr,
+    "dwNetworkGameClient" => OffsetType::Ptr,
+    "dwNetworkGameClient_clientTickCount" => OffsetType::U32,
+    "dwNetworkGameClient_isBackgroundMap" => OffsetType::Bool,
+    "dwNetworkGameClient_maxClients" => OffsetType::U32,
+    "dwNetworkGameClient_serverTickCount" => OffsetType::U32,
+    "dwNetworkGameClient_signOnState" => OffsetType::U32,
+    "dwPlantedC4" => OffsetType::Ptr,
+    "dwSensitivity_sensitivity" => OffsetType::Float,
+    "dwViewRender" => OffsetType::Ptr,
+    "dwWeaponC4" => OffsetType::Ptr,
+    "dwWindowHeight" => OffsetType::U32,
+    "dwWindowWidth" => OffsetType::U32,
+};
+
+/// Returns the type of the value that the offset with the given name points to, if known.
+pub fn offset_type(name: &str) -> Option<OffsetType> {
+    OFFSET_TYPES.get(name).copied()
+}
 
 macro_rules! pattern_map {
     ($($module:ident => {
@@ -145,12 +259,159 @@ pub fn offsets<P: Process + MemoryView>(process: &mut P) -> Result<OffsetMap> {
 
         let view = PeView::from_bytes(&buf)?;
 
-        map.insert(module_name.to_string(), offsets(view));
+        let offsets = offsets(view)
+            .into_iter()
+            .map(|(name, rva)| (name, rva as umem))
+            .collect();
+
+        map.insert(module_name.to_string(), offsets);
     }
 
     Ok(map)
 }
 
+/// Finds the offsets of user-defined byte signatures.
+pub fn signature_offsets<P: Process + MemoryView>(
+    process: &mut P,
+    signatures: &[Signature],
+) -> Result<OffsetMap> {
+    let mut modules = BTreeMap::<_, Vec<_>>::new();
+
+    for signature in signatures {
+        modules
+            .entry(signature.module.as_str())
+            .or_default()
+            .push(signature);
+    }
+
+    let mut builder = OffsetMapBuilder::default();
+
+    for (module_name, signatures) in modules {
+        let module = process.module_by_name(module_name)?;
+
+        let buf = process
+            .read_raw(module.base, module.size as _)
+            .data_part()?;
+
+        let scanner = PatternScanner::new(&buf);
+
+        for signature in signatures {
+            let rva = match signature.rip_offset {
+                Some(offset) => scanner.scan_rip(&signature.signature, offset),
+                None => scanner.scan(&signature.signature),
+            };
+
+            let Some(rva) = rva else {
+                error!("signature not found: {}", signature.name);
+
+                continue;
+            };
+
+            debug!(
+                "found \"{}\" at {:#X} ({} + {:#X})",
+                signature.name,
+                module.base.to_umem() + rva as umem,
+                module_name,
+                rva
+            );
+
+            builder.insert_offset(module_name, &signature.name, rva as umem);
+        }
+    }
+
+    Ok(builder.finish())
+}
+
+/// Adds the base address of each module to its offsets.
+pub fn to_absolute<P: Process + MemoryView>(
+    process: &mut P,
+    offsets: &mut OffsetMap,
+) -> Result<()> {
+    for (module_name, offsets) in offsets {
+        let base = process.module_by_name(module_name)?.base.to_umem();
+
+        for value in offsets.values_mut() {
+            *value += base;
+        }
+    }
+
+    Ok(())
+}
+
+/// The name of the offset that holds the build number of the game.
+pub const BUILD_NUMBER_OFFSET: &str = "dwBuildNumber";
+
+/// The module that has the build number offset.
+pub const BUILD_NUMBER_MODULE: &str = "engine2.dll";
+
+/// Reads the build number of the game without analyzing the other offsets.
+pub fn current_build_number<P: Process + MemoryView>(process: &mut P) -> Result<u32> {
+    let module = process.module_by_name(BUILD_NUMBER_MODULE)?;
+
+    let buf = process
+        .read_raw(module.base, module.size as _)
+        .data_part()?;
+
+    let view = PeView::from_bytes(&buf)?;
+
+    let Some(offset) = engine2::offsets(view).get(BUILD_NUMBER_OFFSET).copied() else {
+        bail!("outdated build number pattern");
+    };
+
+    Ok(process.read::<u32>(module.base + offset).data_part()?)
+}
+
+/// Reads the build number of the game from the process, using an offset of `offsets` that is
+/// named [`BUILD_NUMBER_OFFSET`] (`dwBuildNumber`), as it's found by [`offsets`].
+///
+/// The offset is relative to the base of its module, or an absolute address with
+/// [`AddressMode::Absolute`], and points to a `u32`. The offsets of `module_hint` (e.g.
+/// `Some("engine2.dll")`) are searched first, followed by those of all other modules, so a
+/// user-defined signature with the same name in another module also works.
+///
+/// Without the offset in any module (e.g. if only the schemas were analyzed, or the module was
+/// filtered out), [`BUILD_NUMBER_MODULE`] is scanned for it instead.
+pub fn read_build_number_from_process<P: Process + MemoryView>(
+    process: &mut P,
+    offsets: &OffsetMap,
+    address_mode: AddressMode,
+    module_hint: Option<&str>,
+) -> Option<u32> {
+    if !offsets
+        .values()
+        .any(|offsets| offsets.contains_key(BUILD_NUMBER_OFFSET))
+    {
+        debug!(
+            "no {} offset, scanning {} for it",
+            BUILD_NUMBER_OFFSET, BUILD_NUMBER_MODULE
+        );
+
+        return current_build_number(process)
+            .inspect_err(|err| debug!("failed to scan for the build number: {}", err))
+            .ok();
+    }
+
+    let hinted = module_hint.and_then(|module_name| offsets.get_key_value(module_name));
+
+    let others = offsets
+        .iter()
+        .filter(|(module_name, _)| Some(module_name.as_str()) != module_hint);
+
+    hinted
+        .into_iter()
+        .chain(others)
+        .find_map(|(module_name, offsets)| {
+            let offset = *offsets.get(BUILD_NUMBER_OFFSET)?;
+
+            let address = match address_mode {
+                AddressMode::Rva => process.module_by_name(module_name).ok()?.base + offset,
+                AddressMode::Absolute => Address::from(offset),
+            };
+
+            process.read::<u32>(address).data_part().ok()
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -286,6 +547,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn builder_duplicates() {
+        let mut builder = OffsetMapBuilder::from(OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+        )]));
+
+        assert_eq!(
+            builder.insert_offset("client.dll", "dwViewMatrix", 0x232EAC0),
+            None
+        );
+        assert_eq!(
+            builder.insert_offset("client.dll", "dwEntityList", 0x24CED60),
+            Some(0x24CED50)
+        );
+        assert_eq!(
+            builder.insert_offset("engine2.dll", "dwEntityList", 0x5A1234),
+            None
+        );
+
+        assert_eq!(builder.len(), 3);
+
+        let map = builder.finish();
+
+        assert_eq!(map["client.dll"]["dwEntityList"], 0x24CED60);
+        assert_eq!(map["engine2.dll"].len(), 1);
+    }
+
     fn setup() -> Result<IntoProcessInstanceArcBox<'static>> {
         static LOGGER: Once = Once::new();
 
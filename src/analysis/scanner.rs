@@ -0,0 +1,69 @@
+/// Scans a module image for byte signatures in the common `"48 8B 05 ? ? ? ?"` format, where `?`
+/// (or `??`) matches any byte.
+pub struct PatternScanner<'a> {
+    image: &'a [u8],
+}
+
+impl<'a> PatternScanner<'a> {
+    /// Creates a scanner for a module image that is mapped the same way it is in memory, so that
+    /// offsets into it are RVAs.
+    pub fn new(image: &'a [u8]) -> Self {
+        Self { image }
+    }
+
+    /// Parses a signature into its bytes, with `None` for wildcards.
+    pub fn parse(pattern: &str) -> Option<Vec<Option<u8>>> {
+        let bytes: Option<Vec<_>> = pattern
+            .split_whitespace()
+            .map(|byte| match byte {
+                "?" | "??" => Some(None),
+                _ if byte.len() == 2 => u8::from_str_radix(byte, 16).ok().map(Some),
+                _ => None,
+            })
+            .collect();
+
+        bytes.filter(|bytes| !bytes.is_empty())
+    }
+
+    /// Returns the RVA of the first match of `pattern`, or `None` if there is no match or the
+    /// pattern is invalid.
+    pub fn scan(&self, pattern: &str) -> Option<usize> {
+        let bytes = Self::parse(pattern)?;
+
+        self.image.windows(bytes.len()).position(|window| {
+            window
+                .iter()
+                .zip(&bytes)
+                .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected))
+        })
+    }
+
+    /// Resolves the RIP-relative displacement at `offset` bytes into the first match of `pattern`,
+    /// assuming the displacement is the last operand of the instruction.
+    pub fn scan_rip(&self, pattern: &str, offset: usize) -> Option<usize> {
+        let rva = self.scan(pattern)? + offset;
+
+        let disp = self.image.get(rva..rva + 4)?;
+        let disp = i32::from_le_bytes(disp.try_into().ok()?);
+
+        (rva + 4).checked_add_signed(disp as isize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scan() {
+        let image = [0xCC, 0x48, 0x8B, 0x05, 0x10, 0x00, 0x00, 0x00, 0xC3];
+
+        let scanner = PatternScanner::new(&image);
+
+        assert_eq!(scanner.scan("48 8B 05 ? ? ? ?"), Some(1));
+        assert_eq!(scanner.scan("8B ?? 10"), Some(2));
+        assert_eq!(scanner.scan("48 8B 06"), None);
+        assert_eq!(scanner.scan("48 8B 0"), None);
+        assert_eq!(scanner.scan_rip("48 8B 05 ? ? ? ?", 3), Some(0x18));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{Result, bail};
+
+use super::Class;
+
+/// The inheritance graph of the schema classes of a module, with an edge from each parent class
+/// to its children.
+///
+/// Parent classes that aren't part of the module are ignored, since they're declared elsewhere.
+pub struct SchemaGraph<'a> {
+    classes: &'a [Class],
+    children: Vec<Vec<usize>>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<'a> SchemaGraph<'a> {
+    pub fn new(classes: &'a [Class]) -> Self {
+        let mut indices = HashMap::with_capacity(classes.len());
+
+        for (i, class) in classes.iter().enumerate() {
+            indices.entry(class.name.as_str()).or_insert(i);
+        }
+
+        let mut children = vec![Vec::new(); classes.len()];
+
+        let parents: Vec<_> = classes
+            .iter()
+            .enumerate()
+            .map(|(i, class)| {
+                let parent = class
+                    .parent_name
+                    .as_deref()
+                    .and_then(|name| indices.get(name).copied())
+                    .filter(|parent| *parent != i)?;
+
+                children[parent].push(i);
+
+                Some(parent)
+            })
+            .collect();
+
+        Self {
+            classes,
+            children,
+            parents,
+        }
+    }
+
+    /// Returns the classes in an order where every parent class comes before its children, using
+    /// Kahn's algorithm.
+    ///
+    /// Classes without a dependency between them keep their original order. Fails if the classes
+    /// inherit from each other in a cycle, which can only be the result of a corrupted read.
+    pub fn topological_order(&self) -> Result<Vec<&'a Class>> {
+        if let Some(cycle) = self.find_cycle() {
+            bail!(
+                "cyclic schema class inheritance: {} -> {}",
+                cycle.join(" -> "),
+                cycle[0]
+            );
+        }
+
+        let mut in_degrees: Vec<_> = self
+            .parents
+            .iter()
+            .map(|parent| parent.is_some() as usize)
+            .collect();
+
+        let mut queue: VecDeque<_> = (0..self.classes.len())
+            .filter(|i| in_degrees[*i] == 0)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.classes.len());
+
+        while let Some(i) = queue.pop_front() {
+            order.push(&self.classes[i]);
+
+            for child in &self.children[i] {
+                in_degrees[*child] -= 1;
+
+                if in_degrees[*child] == 0 {
+                    queue.push_back(*child);
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Returns the names of the classes in the first inheritance cycle, from a parent to its child,
+    /// using a depth-first search.
+    pub fn find_cycle(&self) -> Option<Vec<&'a str>> {
+        let mut visited = vec![false; self.classes.len()];
+        let mut in_stack = vec![false; self.classes.len()];
+
+        for start in 0..self.classes.len() {
+            let mut stack: Vec<usize> = Vec::new();
+            let mut current = Some(start);
+
+            // Every class has at most one parent, so the search only has to follow the parents.
+            while let Some(i) = current {
+                if in_stack[i] {
+                    let pos = stack.iter().position(|j| *j == i).unwrap();
+
+                    let mut cycle: Vec<_> = stack[pos..]
+                        .iter()
+                        .rev()
+                        .map(|j| self.classes[*j].name.as_str())
+                        .collect();
+
+                    // Start the cycle at the class where it was detected.
+                    cycle.rotate_right(1);
+
+                    return Some(cycle);
+                }
+
+                if visited[i] {
+                    break;
+                }
+
+                visited[i] = true;
+                in_stack[i] = true;
+
+                stack.push(i);
+
+                current = self.parents[i];
+            }
+
+            for i in stack {
+                in_stack[i] = false;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn class(name: &str, parent_name: Option<&str>) -> Class {
+        Class {
+            name: name.to_string(),
+            module_name: "client.dll".to_string(),
+            parent_name: parent_name.map(str::to_string),
+            size: 0,
+            alignment: 8,
+            metadata: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parents_before_children() {
+        let classes = [
+            class("C_CSPlayerPawn", Some("C_BasePlayerPawn")),
+            class("C_BaseEntity", Some("CEntityInstance")),
+            class("C_BasePlayerPawn", Some("C_BaseModelEntity")),
+            class("CGameSceneNode", None),
+            class("C_BaseModelEntity", Some("C_BaseEntity")),
+        ];
+
+        let order: Vec<_> = SchemaGraph::new(&classes)
+            .topological_order()
+            .unwrap()
+            .into_iter()
+            .map(|class| class.name.as_str())
+            .collect();
+
+        assert_eq!(
+            order,
+            [
+                "C_BaseEntity",
+                "CGameSceneNode",
+                "C_BaseModelEntity",
+                "C_BasePlayerPawn",
+                "C_CSPlayerPawn"
+            ]
+        );
+    }
+
+    #[test]
+    fn inheritance_cycle() {
+        let classes = [
+            class("CGameSceneNode", None),
+            class("C_A", Some("C_C")),
+            class("C_B", Some("C_A")),
+            class("C_C", Some("C_B")),
+            class("C_D", Some("C_A")),
+        ];
+
+        let graph = SchemaGraph::new(&classes);
+
+        assert_eq!(graph.find_cycle().unwrap(), ["C_A", "C_B", "C_C"]);
+
+        assert_eq!(
+            graph.topological_order().unwrap_err().to_string(),
+            "cyclic schema class inheritance: C_A -> C_B -> C_C -> C_A"
+        );
+    }
+}
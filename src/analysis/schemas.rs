@@ -1,9 +1,10 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::ffi::CStr;
 
 use anyhow::{Result, bail};
 
-use log::debug;
+use log::{debug, warn};
 
 use memflow::prelude::v1::*;
 
@@ -12,6 +13,9 @@ use pelite::pe64::{Pe, PeView};
 
 use serde::{Deserialize, Serialize};
 
+use super::SchemaGraph;
+
+use crate::memory::batch::BatchReader;
 use crate::source2::*;
 
 pub type SchemaMap = BTreeMap<String, (Vec<Class>, Vec<Enum>)>;
@@ -28,15 +32,103 @@ pub struct Class {
     pub name: String,
     pub module_name: String,
     pub parent_name: Option<String>,
+    pub size: i32,
+    pub alignment: u8,
     pub metadata: Vec<ClassMetadata>,
     pub fields: Vec<ClassField>,
 }
 
+impl Class {
+    /// Returns the union group of each field that shares its offset with other fields. The groups
+    /// are numbered in the order of their offsets.
+    pub fn union_groups(&self) -> BTreeMap<&str, usize> {
+        let mut offsets = BTreeMap::<_, Vec<_>>::new();
+
+        for field in &self.fields {
+            offsets
+                .entry(field.offset)
+                .or_default()
+                .push(field.name.as_str());
+        }
+
+        offsets
+            .into_values()
+            .filter(|names| names.len() > 1)
+            .enumerate()
+            .flat_map(|(group, names)| names.into_iter().map(move |name| (name, group)))
+            .collect()
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ClassField {
     pub name: String,
     pub type_name: String,
     pub offset: i32,
+    pub alignment: Option<u8>,
+}
+
+impl ClassField {
+    /// Returns the element type and count, if the type of the field is a fixed-size array, e.g.
+    /// `char` and `128` for `char[128]`.
+    ///
+    /// The count of a multidimensional array is its outermost dimension, which is the first one
+    /// after the base type, and its element type is an array of the remaining dimensions, e.g.
+    /// `float32[4]` and `3` for `float32[3][4]`.
+    pub fn array_type(&self) -> Option<(Cow<'_, str>, usize)> {
+        let mut base_type = self.type_name.as_str();
+
+        while let Some((rest, count)) = base_type
+            .strip_suffix(']')
+            .and_then(|type_name| type_name.rsplit_once('['))
+        {
+            count.parse::<usize>().ok()?;
+
+            base_type = rest;
+        }
+
+        let (count, inner_dimensions) = self.type_name[base_type.len()..]
+            .strip_prefix('[')?
+            .split_once(']')?;
+
+        let element_type = if inner_dimensions.is_empty() {
+            Cow::Borrowed(base_type)
+        } else {
+            Cow::Owned(format!("{}{}", base_type, inner_dimensions))
+        };
+
+        Some((element_type, count.parse().ok()?))
+    }
+
+    /// Returns the name of the template and its parameters, if the type of the field is a
+    /// template, e.g. `CHandle` and `["C_BaseEntity"]` for `CHandle<C_BaseEntity>`.
+    ///
+    /// Nested templates are kept as a single parameter.
+    pub fn template_params(&self) -> Option<(&str, Vec<&str>)> {
+        let (name, params) = self.type_name.split_once('<')?;
+        let params = params.strip_suffix('>')?;
+
+        let mut depth = 0;
+        let mut start = 0;
+        let mut result = Vec::new();
+
+        for (i, c) in params.char_indices() {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                ',' if depth == 0 => {
+                    result.push(&params[start..i]);
+
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+
+        result.push(&params[start..]);
+
+        Some((name, result))
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -67,10 +159,23 @@ pub fn schemas<P: Process + MemoryView>(process: &mut P) -> Result<SchemaMap> {
     Ok(type_scopes
         .into_iter()
         .map(|type_scope| {
-            (
-                type_scope.module_name,
-                (type_scope.classes, type_scope.enums),
-            )
+            // Parent classes are declared before their children, so that the generated files
+            // compile without forward declarations. A cycle only affects its own module, which is
+            // kept in its original order.
+            let order = SchemaGraph::new(&type_scope.classes)
+                .topological_order()
+                .map(|order| order.into_iter().cloned().collect());
+
+            let classes = match order {
+                Ok(classes) => classes,
+                Err(err) => {
+                    warn!("{} in {}", err, type_scope.module_name);
+
+                    type_scope.classes
+                }
+            };
+
+            (type_scope.module_name, (classes, type_scope.enums))
         })
         .collect())
 }
@@ -107,12 +212,16 @@ fn read_class_binding(
     });
 
     let fields = read_class_binding_fields(mem, &binding)?;
+
+    check_array_sizes(&name, binding.size, &fields);
     let metadata = read_class_binding_metadata(mem, &binding)?;
 
     Ok(Class {
         name,
         module_name,
         parent_name,
+        size: binding.size,
+        alignment: binding.alignment,
         metadata,
         fields,
     })
@@ -126,29 +235,132 @@ fn read_class_binding_fields(
         return Ok(Vec::new());
     }
 
-    (0..binding.field_count).try_fold(Vec::new(), |mut acc, i| {
-        let field = mem.read_ptr(binding.fields.at(i as _)).data_part()?;
+    // Classes can have hundreds of fields, so their data and types are each read in one batch.
+    let mut batch = BatchReader::new();
 
-        if field.r#type.is_null() {
-            return Ok(acc);
+    let handles: Vec<_> = (0..binding.field_count)
+        .map(|i| batch.queue::<SchemaClassFieldData>(binding.fields.at(i as _).address()))
+        .collect();
+
+    batch.execute(mem)?;
+
+    let fields: Vec<_> = handles
+        .into_iter()
+        .map(|handle| batch.get(handle))
+        .filter(|field| !field.r#type.is_null())
+        .collect();
+
+    let mut batch = BatchReader::new();
+
+    let type_handles: Vec<_> = fields
+        .iter()
+        .map(|field| batch.queue::<SchemaType>(field.r#type.address()))
+        .collect();
+
+    batch.execute(mem)?;
+
+    fields
+        .into_iter()
+        .zip(type_handles)
+        .try_fold(Vec::new(), |mut acc, (field, handle)| {
+            let name = mem.read_utf8_lossy(field.name.address(), 128).data_part()?;
+            let r#type = batch.get(handle);
+
+            let type_name = mem
+                .read_utf8_lossy(r#type.name.address(), 128)
+                .data_part()?
+                .replace(" ", "");
+
+            let alignment = read_type_alignment(mem, &r#type, &type_name);
+
+            acc.push(ClassField {
+                name,
+                type_name,
+                offset: field.offset,
+                alignment,
+            });
+
+            Ok(acc)
+        })
+}
+
+/// Warns about the fixed-size arrays of built-in types that don't fit into the space until the next
+/// field, which means that their type or count wasn't read correctly.
+fn check_array_sizes(class_name: &str, class_size: i32, fields: &[ClassField]) {
+    for field in fields {
+        let Some((element_type, count)) = field.array_type() else {
+            continue;
+        };
+
+        let Some(element_size) = builtin_size(&element_type) else {
+            continue;
+        };
+
+        let end = fields
+            .iter()
+            .map(|field| field.offset)
+            .filter(|offset| *offset > field.offset)
+            .min()
+            .unwrap_or(class_size);
+
+        let size = element_size as i64 * count as i64;
+        let space = (end - field.offset) as i64;
+
+        if size > space {
+            warn!(
+                "array field {}::{} of type {} needs {:#X} bytes, but only has {:#X}",
+                class_name, field.name, field.type_name, size, space
+            );
         }
+    }
+}
 
-        let name = mem.read_utf8_lossy(field.name.address(), 128).data_part()?;
-        let r#type = mem.read_ptr(field.r#type).data_part()?;
+/// Returns the size of a built-in type, which is also its alignment.
+fn builtin_size(type_name: &str) -> Option<u8> {
+    match type_name {
+        "bool" | "char" | "int8" | "uint8" => Some(1),
+        "int16" | "uint16" => Some(2),
+        "int32" | "uint32" | "float32" => Some(4),
+        "int64" | "uint64" | "float64" => Some(8),
+        _ => None,
+    }
+}
 
-        let type_name = mem
-            .read_utf8_lossy(r#type.name.address(), 128)
-            .data_part()?
-            .replace(" ", "");
+/// Determines the alignment of a type, if it can be derived from its category.
+fn read_type_alignment(
+    mem: &mut impl MemoryView,
+    r#type: &SchemaType,
+    type_name: &str,
+) -> Option<u8> {
+    match r#type.type_category {
+        SchemaTypeCategory::BuiltIn => builtin_size(type_name),
+        SchemaTypeCategory::Ptr => Some(8),
+        SchemaTypeCategory::FixedArray => {
+            let element_ptr = unsafe { r#type.value.array.element };
+            let element = mem.read_ptr(element_ptr).data_part().ok()?;
+
+            let element_name = mem
+                .read_utf8_lossy(element.name.address(), 128)
+                .data_part()
+                .ok()?
+                .replace(" ", "");
+
+            read_type_alignment(mem, &element, &element_name)
+        }
+        SchemaTypeCategory::DeclaredClass => {
+            let binding_ptr = unsafe { r#type.value.class_binding };
+            let binding = mem.read_ptr(binding_ptr).data_part().ok()?;
 
-        acc.push(ClassField {
-            name,
-            type_name,
-            offset: field.offset,
-        });
+            Some(binding.alignment)
+        }
+        SchemaTypeCategory::DeclaredEnum => {
+            let binding_ptr = unsafe { r#type.value.enum_binding };
+            let binding = mem.read_ptr(binding_ptr).data_part().ok()?;
 
-        Ok(acc)
-    })
+            Some(binding.alignment)
+        }
+        _ => None,
+    }
 }
 
 fn read_class_binding_metadata(
@@ -328,3 +540,90 @@ fn read_type_scopes(
         Ok(acc)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_groups() {
+        let field = |name: &str, offset| ClassField {
+            name: name.to_string(),
+            type_name: "uint32".to_string(),
+            offset,
+            alignment: Some(4),
+        };
+
+        let class = Class {
+            name: "CNetworkVarChainer".to_string(),
+            module_name: "client.dll".to_string(),
+            parent_name: None,
+            size: 0x10,
+            alignment: 4,
+            metadata: Vec::new(),
+            fields: vec![
+                field("m_nFlags", 0xC),
+                field("m_nA", 0x0),
+                field("m_nValue", 0x4),
+                field("m_nB", 0x0),
+                field("m_nBits", 0xC),
+            ],
+        };
+
+        assert_eq!(
+            class.union_groups(),
+            BTreeMap::from([("m_nA", 0), ("m_nB", 0), ("m_nBits", 1), ("m_nFlags", 1)])
+        );
+    }
+
+    #[test]
+    fn array_type() {
+        let field = |type_name: &str| ClassField {
+            name: "m_field".to_string(),
+            type_name: type_name.to_string(),
+            offset: 0,
+            alignment: None,
+        };
+
+        assert_eq!(field("char[128]").array_type(), Some(("char".into(), 128)));
+        assert_eq!(
+            field("float32[3][4]").array_type(),
+            Some(("float32[4]".into(), 3))
+        );
+        assert_eq!(
+            field("int32[2][3][4]").array_type(),
+            Some(("int32[3][4]".into(), 2))
+        );
+        assert_eq!(
+            field("CUtlVector<char[4]>[2]").array_type(),
+            Some(("CUtlVector<char[4]>".into(), 2))
+        );
+        assert_eq!(field("CUtlVector<char[4]>").array_type(), None);
+        assert_eq!(field("Vector").array_type(), None);
+    }
+
+    #[test]
+    fn template_params() {
+        let field = |type_name: &str| ClassField {
+            name: "m_field".to_string(),
+            type_name: type_name.to_string(),
+            offset: 0,
+            alignment: None,
+        };
+
+        assert_eq!(field("int32").template_params(), None);
+
+        assert_eq!(
+            field("CHandle<C_BaseEntity>").template_params(),
+            Some(("CHandle", vec!["C_BaseEntity"]))
+        );
+
+        assert_eq!(
+            field("CUtlHashMap<int32,CUtlVector<CHandle<C_BaseEntity>>>").template_params(),
+            Some((
+                "CUtlHashMap",
+                vec!["int32", "CUtlVector<CHandle<C_BaseEntity>>"]
+            ))
+        );
+    }
+}
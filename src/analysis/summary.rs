@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::AnalysisResult;
+
+impl fmt::Display for AnalysisResult {
+    /// Writes the number of entries of each category on a single line, e.g. `buttons: 14,
+    /// interfaces: 37 in 6 modules, offsets: 143 in 5 modules, ...`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (classes, enums) = self.schemas.values().fold(
+            (0, 0),
+            |(classes, enums), (module_classes, module_enums)| {
+                (classes + module_classes.len(), enums + module_enums.len())
+            },
+        );
+
+        write!(
+            f,
+            "buttons: {}, interfaces: {}, offsets: {}, chains: {}, schemas: {} classes and {} enums in {} modules, vtables: {}",
+            self.buttons.len(),
+            ModuleCount(&self.interfaces),
+            ModuleCount(&self.offsets),
+            ModuleCount(&self.chains),
+            classes,
+            enums,
+            self.schemas.len(),
+            ModuleCount(&self.vtables),
+        )
+    }
+}
+
+/// Displays the number of entries in a map of modules, e.g. `143 in 5 modules`.
+struct ModuleCount<'a, T>(&'a BTreeMap<String, BTreeMap<String, T>>);
+
+impl<T> fmt::Display for ModuleCount<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: usize = self.0.values().map(|entries| entries.len()).sum();
+
+        write!(f, "{} in {} modules", entries, self.0.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::Class;
+
+    #[test]
+    fn summary() {
+        let result = AnalysisResult {
+            buttons: BTreeMap::from([("attack".to_string(), 0x1A2B3C)]),
+            offsets: BTreeMap::from([
+                (
+                    "client.dll".to_string(),
+                    BTreeMap::from([
+                        ("dwEntityList".to_string(), 0x24CED50),
+                        ("dwViewMatrix".to_string(), 0x232EAC0),
+                    ]),
+                ),
+                (
+                    "engine2.dll".to_string(),
+                    BTreeMap::from([("dwBuildNumber".to_string(), 0x5A1234)]),
+                ),
+            ]),
+            schemas: BTreeMap::from([(
+                "client.dll".to_string(),
+                (
+                    vec![Class {
+                        name: "C_BaseEntity".to_string(),
+                        module_name: "client.dll".to_string(),
+                        parent_name: None,
+                        size: 0x10,
+                        alignment: 8,
+                        metadata: Vec::new(),
+                        fields: Vec::new(),
+                    }],
+                    Vec::new(),
+                ),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            result.to_string(),
+            "buttons: 1, interfaces: 0 in 0 modules, offsets: 3 in 2 modules, chains: 0 in 0 modules, schemas: 1 classes and 0 enums in 1 modules, vtables: 0 in 0 modules"
+        );
+    }
+}
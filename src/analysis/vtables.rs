@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use log::debug;
+
+use memflow::prelude::v1::*;
+
+use serde::{Deserialize, Serialize};
+
+use super::InterfaceMap;
+
+pub type VtableMap = BTreeMap<String, BTreeMap<String, Vec<VtableEntry>>>;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct VtableEntry {
+    pub index: usize,
+    pub address: umem,
+}
+
+pub fn vtables<P: Process + MemoryView>(
+    process: &mut P,
+    interfaces: &InterfaceMap,
+    max_entries: usize,
+) -> Result<VtableMap> {
+    let mut map = BTreeMap::new();
+
+    for (module_name, ifaces) in interfaces {
+        let module = process.module_by_name(module_name)?;
+
+        let vtables: BTreeMap<_, _> = ifaces
+            .iter()
+            .filter_map(|(name, instance_rva)| {
+                let entries =
+                    read_vtable(process, &module, module.base + *instance_rva, max_entries)?;
+
+                debug!(
+                    "found {} vtable entries for \"{}\" ({})",
+                    entries.len(),
+                    name,
+                    module_name
+                );
+
+                Some((name.clone(), entries))
+            })
+            .collect();
+
+        if !vtables.is_empty() {
+            map.insert(module_name.clone(), vtables);
+        }
+    }
+
+    Ok(map)
+}
+
+fn is_in_module(module: &ModuleInfo, addr: Address) -> bool {
+    addr >= module.base && addr < module.base + module.size
+}
+
+fn read_vtable(
+    mem: &mut impl MemoryView,
+    module: &ModuleInfo,
+    instance_addr: Address,
+    max_entries: usize,
+) -> Option<Vec<VtableEntry>> {
+    let vtable_addr = mem.read_addr64(instance_addr).data_part().ok()?;
+
+    if !is_in_module(module, vtable_addr) {
+        return None;
+    }
+
+    let mut entries = Vec::new();
+
+    for index in 0..max_entries {
+        let Ok(fn_addr) = mem
+            .read_addr64(vtable_addr + (index * 8) as umem)
+            .data_part()
+        else {
+            break;
+        };
+
+        // The vtable ends at the first entry that doesn't point into the module.
+        if !is_in_module(module, fn_addr) {
+            break;
+        }
+
+        entries.push(VtableEntry {
+            index,
+            address: fn_addr.to_umem() - module.base.to_umem(),
+        });
+    }
+
+    (!entries.is_empty()).then_some(entries)
+}
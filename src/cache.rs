@@ -0,0 +1,135 @@
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use flate2::read::GzDecoder;
+
+use log::{debug, warn};
+
+use memflow::prelude::v1::*;
+
+use serde_json::json;
+
+use crate::analysis::{self, AddressMode, AnalysisResult};
+use crate::config::DumpConfig;
+use crate::output::Compression;
+
+/// A cached analysis result for a build of the game, stored as gzip-compressed JSON.
+pub struct Cache {
+    path: PathBuf,
+}
+
+impl Cache {
+    /// Returns the cache entry for the running build of the game, or `None` if caching is disabled
+    /// or the build number can't be read.
+    pub fn open<P: Process + MemoryView>(process: &mut P, config: &DumpConfig) -> Option<Self> {
+        // Absolute addresses are only valid for the process they were read from.
+        if !config.cache || config.address_mode == AddressMode::Absolute {
+            return None;
+        }
+
+        let dir = config
+            .cache_dir
+            .clone()
+            .or_else(|| dirs::cache_dir().map(|dir| dir.join("cs2-dumper")))?;
+
+        let build_number = match analysis::current_build_number(process) {
+            Ok(build_number) => build_number,
+            Err(err) => {
+                warn!("failed to read build number, skipping cache: {}", err);
+
+                return None;
+            }
+        };
+
+        // The analysis options are part of the key, since they change what is analyzed. The strict
+        // options only decide whether the analysis fails, so they're left out.
+        let mut categories = config.categories.clone();
+
+        categories.sort();
+
+        let options = json!({
+            "categories": categories,
+            "signatures": config.signatures,
+            "chains": config.chains,
+            "vtable_size": config.vtable_size,
+        });
+
+        let file_name = format!(
+            "{}-{:016x}.json.gz",
+            build_number,
+            fnv1a(options.to_string().as_bytes())
+        );
+
+        Some(Self {
+            path: dir.join(file_name),
+        })
+    }
+
+    /// Reads the cached result, if there is one.
+    pub fn load(&self) -> Option<AnalysisResult> {
+        if !self.path.is_file() {
+            return None;
+        }
+
+        match self.read() {
+            Ok(result) => {
+                debug!("loaded cached result from {}", self.path.display());
+
+                Some(result)
+            }
+            Err(err) => {
+                warn!("failed to read cache {}: {}", self.path.display(), err);
+
+                None
+            }
+        }
+    }
+
+    pub fn store(&self, result: &AnalysisResult) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let content = serde_json::to_vec(result)?;
+
+        fs::write(&self.path, Compression::Gzip.compress(&content)?)?;
+
+        debug!("cached result in {}", self.path.display());
+
+        Ok(())
+    }
+
+    fn read(&self) -> Result<AnalysisResult> {
+        let mut content = String::new();
+
+        GzDecoder::new(fs::File::open(&self.path)?).read_to_string(&mut content)?;
+
+        Ok(serde_json::from_str(&content)?)
+    }
+}
+
+/// Returns the 64-bit FNV-1a hash of `bytes`. Unlike the hasher of the standard library, the
+/// algorithm is fixed, so the names of the cache files stay the same across Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+    const PRIME: u64 = 0x100000001B3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fnv1a_hash() {
+        assert_eq!(fnv1a(b""), 0xCBF29CE484222325);
+        assert_eq!(fnv1a(b"a"), 0xAF63DC4C8601EC8C);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171F73967E8);
+    }
+}
@@ -0,0 +1,398 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, anyhow, bail};
+
+use regex::Regex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::{AddressMode, CATEGORIES, MergeStrategy, PatternScanner};
+use crate::memory::retry::RetryConfig;
+use crate::output::{
+    Compression, DEFAULT_FILE_TYPES, FILE_TYPES, IndentKind, LineEnding, ValueFormat,
+    canonical_file_type,
+};
+
+/// The configuration file that is loaded from the working directory if no path is specified.
+pub const DEFAULT_CONFIG_PATH: &str = "cs2-dumper.toml";
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DumpConfig {
+    /// The output directory to write the generated files to.
+    pub out_dir: PathBuf,
+
+    /// Whether to write the generated files to a subdirectory of the output directory that is
+    /// named after the time of the dump.
+    pub timestamp_dir: bool,
+
+    /// The types of files to generate.
+    pub file_types: Vec<String>,
+
+    /// The item categories to analyze and generate files for.
+    pub categories: Vec<String>,
+
+    /// Whether to indent code files with spaces or tabs.
+    pub indent_style: IndentKind,
+
+    /// The number of spaces to use per indentation level. Ignored when indenting with tabs.
+    pub indent_size: usize,
+
+    /// The line ending of the generated files. Defaults to CRLF on Windows and LF elsewhere.
+    pub line_ending: LineEnding,
+
+    /// Whether to write offset values in code files as hexadecimal or decimal literals.
+    pub value_format: ValueFormat,
+
+    /// Whether to write the names of offsets, buttons and interfaces in the case convention of
+    /// constants in each language, rather than as they are in the game.
+    pub language_case: bool,
+
+    /// The parts of the namespace to put the generated code in (e.g. `["Acme", "CS2"]`), instead of
+    /// `cs2_dumper` in C++, Rust and TypeScript, `CS2Dumper` in C# and `com.cs2dumper` in Java.
+    pub root_namespace: Option<Vec<String>>,
+
+    /// Whether to start each file with a comment that has the time of the dump and the build
+    /// number.
+    pub write_banner: bool,
+
+    /// The text of the banner instead of the default one, where `{timestamp}`, `{build_number}` and
+    /// `{url}` are replaced. Each line is turned into a comment.
+    pub banner_template: Option<String>,
+
+    /// Whether to write the schema classes in C# as `[StructLayout(LayoutKind.Explicit)]` structs
+    /// with a `[FieldOffset]` on each field, instead of static classes of offsets.
+    pub cs_explicit_layout: bool,
+
+    /// Whether to write an `unsafe` method in C# that reads the value of each offset with a known
+    /// type from the base address of its module.
+    pub cs_helpers: bool,
+
+    /// Whether to write the generated files to stdout instead of the output directory.
+    pub stdout: bool,
+
+    /// Whether to generate the files without writing them, and print the start of each file
+    /// instead.
+    pub dry_run: bool,
+
+    /// Whether to read back each written file and check that it matches the generated content.
+    pub validate: bool,
+
+    /// The output directory of a previous dump to generate a diff against.
+    pub diff: Option<PathBuf>,
+
+    /// The compression to apply to the generated files.
+    pub compress: Compression,
+
+    /// Whether to append the build number to the name of each generated file, except for
+    /// `info.json` and `dump.db`.
+    pub versioned_file_names: bool,
+
+    /// Whether to write the generated files to a `dump.zip` archive instead of separate files.
+    pub zip: bool,
+
+    /// Whether to write a `Cargo.toml` and `lib.rs` that make the generated Rust files a crate.
+    pub rust_crate: bool,
+
+    /// Whether to put the generated Python files in a `cs2_offsets` package with a `setup.py`.
+    pub python_package: bool,
+
+    /// Whether to put the generated TypeScript files in a `cs2-offsets` npm package.
+    pub npm_package: bool,
+
+    /// Whether to put the generated Go files in a `cs2` package of a Go module.
+    pub go_module: bool,
+
+    /// Whether to write a JSON Schema of the generated JSON files to `results.schema.json`.
+    pub json_schema: bool,
+
+    /// The directory of previous dumps to merge into the result before it's written.
+    pub merge: Option<PathBuf>,
+
+    /// How to resolve entries that differ between the merged dumps.
+    pub merge_strategy: MergeStrategy,
+
+    /// The maximum number of entries to read from each interface vtable.
+    pub vtable_size: usize,
+
+    /// How to retry the analysis of a category when reading the game memory fails.
+    pub retry: RetryConfig,
+
+    /// Whether to reuse the cached result of a previous dump of the same build.
+    pub cache: bool,
+
+    /// The directory to store cached results in. Defaults to `cs2-dumper` in the platform cache
+    /// directory.
+    pub cache_dir: Option<PathBuf>,
+
+    /// Whether to write offsets relative to their module or as absolute addresses.
+    pub address_mode: AddressMode,
+
+    /// Additional offsets to find by scanning for byte signatures.
+    pub signatures: Vec<Signature>,
+
+    /// Whether to fail instead of warning when an offset name is found more than once in a
+    /// module.
+    pub strict: bool,
+
+    /// Whether to fail instead of warning when an offset name is found in multiple modules with
+    /// different values.
+    pub strict_unique_names: bool,
+
+    /// Named pointer chains to include in the output.
+    pub chains: Vec<Chain>,
+
+    /// The directory to load custom `<category>.<file type>.tera` templates from.
+    pub template_dir: Option<PathBuf>,
+
+    /// A YAML file that maps offsets (e.g. `client.dll::dwEntityList`) to descriptions, which are
+    /// written as doc comments in the C++, C# and Rust files.
+    pub annotations: Option<PathBuf>,
+
+    /// A regular expression that module names must match to be included in the output.
+    pub module_filter: Option<String>,
+
+    /// A regular expression that button, pointer chain, interface, offset and vtable names must
+    /// match to be included in the output.
+    pub offset_filter: Option<String>,
+
+    /// A regular expression that schema class and enum names must match to be included in the
+    /// output.
+    pub schema_filter: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Signature {
+    /// The name of the offset.
+    pub name: String,
+
+    /// The name of the module to scan (e.g. `client.dll`).
+    pub module: String,
+
+    /// The byte signature to scan for (e.g. `48 8B 05 ? ? ? ?`).
+    pub signature: String,
+
+    /// The offset of a RIP-relative displacement within the match to resolve. If not set, the
+    /// RVA of the match itself is used.
+    pub rip_offset: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Chain {
+    /// The name of the pointer chain.
+    pub name: String,
+
+    /// The name of the module the chain starts at (e.g. `client.dll`).
+    pub module: String,
+
+    /// The offsets to follow, where all but the last one are dereferenced (e.g. `[0x18, 0x40]`).
+    pub offsets: Vec<usize>,
+}
+
+impl Default for DumpConfig {
+    fn default() -> Self {
+        Self {
+            out_dir: PathBuf::from("output"),
+            timestamp_dir: false,
+            file_types: DEFAULT_FILE_TYPES.iter().map(|s| s.to_string()).collect(),
+            categories: CATEGORIES.iter().map(|s| s.to_string()).collect(),
+            indent_style: IndentKind::Spaces,
+            indent_size: 4,
+            line_ending: LineEnding::native(),
+            value_format: ValueFormat::Hex,
+            language_case: false,
+            root_namespace: None,
+            write_banner: true,
+            banner_template: None,
+            cs_explicit_layout: false,
+            cs_helpers: false,
+            stdout: false,
+            dry_run: false,
+            validate: false,
+            diff: None,
+            compress: Compression::None,
+            versioned_file_names: false,
+            zip: false,
+            rust_crate: false,
+            python_package: false,
+            npm_package: false,
+            go_module: false,
+            json_schema: false,
+            merge: None,
+            merge_strategy: MergeStrategy::LatestWins,
+            vtable_size: 128,
+            retry: RetryConfig::default(),
+            cache: true,
+            cache_dir: None,
+            address_mode: AddressMode::Rva,
+            signatures: Vec::new(),
+            strict: false,
+            strict_unique_names: false,
+            chains: Vec::new(),
+            template_dir: None,
+            annotations: None,
+            module_filter: None,
+            offset_filter: None,
+            schema_filter: None,
+        }
+    }
+}
+
+impl DumpConfig {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let content = fs::read_to_string(path)?;
+        let config: Self = toml::from_str(&content)?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if let Some(file_type) = self
+            .file_types
+            .iter()
+            .find(|file_type| !FILE_TYPES.contains(&canonical_file_type(file_type)))
+        {
+            bail!(
+                "unsupported file type \"{}\" (supported: {})",
+                file_type,
+                FILE_TYPES.join(", ")
+            );
+        }
+
+        if let Some(category) = self
+            .categories
+            .iter()
+            .find(|category| !CATEGORIES.contains(&category.as_str()))
+        {
+            bail!(
+                "unsupported category \"{}\" (supported: {})",
+                category,
+                CATEGORIES.join(", ")
+            );
+        }
+
+        if let Some(signature) = self
+            .signatures
+            .iter()
+            .find(|signature| PatternScanner::parse(&signature.signature).is_none())
+        {
+            bail!(
+                "invalid signature \"{}\" for {}",
+                signature.signature,
+                signature.name
+            );
+        }
+
+        for (i, signature) in self.signatures.iter().enumerate() {
+            if self.signatures[..i]
+                .iter()
+                .any(|other| other.name == signature.name && other.module == signature.module)
+            {
+                bail!(
+                    "duplicate signature \"{}\" for {}",
+                    signature.name,
+                    signature.module
+                );
+            }
+        }
+
+        if self.zip && self.stdout {
+            bail!("the zip archive can't be written to stdout");
+        }
+
+        if self.zip && self.compress != Compression::None {
+            bail!("the files in the zip archive are already compressed");
+        }
+
+        if self.dry_run && (self.stdout || self.zip) {
+            bail!("a dry run doesn't write any files");
+        }
+
+        if self.validate && (self.stdout || self.zip || self.dry_run) {
+            bail!("only files in the output directory can be validated");
+        }
+
+        if self.rust_crate {
+            if !self.file_types.iter().any(|file_type| file_type == "rs") {
+                bail!("the Rust crate requires the rs file type");
+            }
+
+            if self.stdout || self.zip || self.compress != Compression::None {
+                bail!("the Rust crate requires uncompressed files in the output directory");
+            }
+        }
+
+        if self.python_package {
+            if !self.file_types.iter().any(|file_type| file_type == "py") {
+                bail!("the Python package requires the py file type");
+            }
+
+            if self.stdout || self.compress != Compression::None {
+                bail!("the Python package requires uncompressed files in the output directory");
+            }
+        }
+
+        if self.npm_package {
+            if !self.file_types.iter().any(|file_type| file_type == "ts") {
+                bail!("the npm package requires the ts file type");
+            }
+
+            if self.stdout || self.compress != Compression::None {
+                bail!("the npm package requires uncompressed files in the output directory");
+            }
+        }
+
+        if self.go_module {
+            if !self.file_types.iter().any(|file_type| file_type == "go") {
+                bail!("the Go module requires the go file type");
+            }
+
+            if self.stdout || self.compress != Compression::None {
+                bail!("the Go module requires uncompressed files in the output directory");
+            }
+        }
+
+        if self.retry.max_attempts == 0 {
+            bail!("the number of retry attempts must be at least 1");
+        }
+
+        if let Some(chain) = self.chains.iter().find(|chain| chain.offsets.is_empty()) {
+            bail!("empty pointer chain for {}", chain.name);
+        }
+
+        self.module_filter()?;
+        self.offset_filter()?;
+        self.schema_filter()?;
+
+        Ok(())
+    }
+
+    /// Compiles the module filter, if one is set.
+    pub fn module_filter(&self) -> Result<Option<Regex>> {
+        compile_filter("module", self.module_filter.as_deref())
+    }
+
+    /// Compiles the offset filter, if one is set.
+    pub fn offset_filter(&self) -> Result<Option<Regex>> {
+        compile_filter("offset", self.offset_filter.as_deref())
+    }
+
+    /// Compiles the schema filter, if one is set.
+    pub fn schema_filter(&self) -> Result<Option<Regex>> {
+        compile_filter("schema", self.schema_filter.as_deref())
+    }
+}
+
+fn compile_filter(kind: &str, filter: Option<&str>) -> Result<Option<Regex>> {
+    filter
+        .map(|filter| {
+            Regex::new(filter)
+                .map_err(|err| anyhow!("invalid {} filter \"{}\": {}", kind, filter, err))
+        })
+        .transpose()
+}
@@ -1,30 +1,49 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::time::Instant;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
-use clap::{ArgAction, Parser};
+use clap::builder::{PossibleValue, PossibleValuesParser, TypedValueParser};
+use clap::{ArgAction, CommandFactory, Parser};
 
-use log::{LevelFilter, info};
+use clap_complete::Shell;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use log::{LevelFilter, error, info, warn};
 
 use memflow::prelude::v1::*;
 
 use simplelog::*;
 
-use output::Output;
+use analysis::{AddressMode, AnalysisResult, Change, MergeStrategy};
+use cache::Cache;
+use config::{DEFAULT_CONFIG_PATH, DumpConfig};
+use output::{Compression, IndentKind, LineEnding, Output, ProgressCallback, ValueFormat};
+use server::UpdateServer;
 
 mod analysis;
+mod cache;
+mod config;
+mod man_page;
 mod memory;
 mod output;
+mod server;
 mod source2;
 
 #[derive(Debug, Parser)]
-#[command(author, version)]
+#[command(
+    author,
+    version,
+    about = "Dumps the offsets, interfaces and schemas of Counter-Strike 2"
+)]
 struct Args {
     /// The name of the memflow connector to use.
     #[arg(short, long)]
@@ -34,22 +53,210 @@ struct Args {
     #[arg(short = 'a', long)]
     connector_args: Option<String>,
 
+    /// The path to a full memory dump of the system to read the game process from, instead of the
+    /// live system. Requires the coredump connector.
+    #[arg(long, conflicts_with_all = ["connector", "connector_args", "watch"])]
+    dump_file: Option<PathBuf>,
+
+    /// The PID of a QEMU/KVM virtual machine to read the game process from, using the kvm
+    /// connector.
+    #[arg(long, conflicts_with_all = ["connector", "connector_args", "dump_file"])]
+    kvm_pid: Option<u32>,
+
+    /// The path to a TOML configuration file. Defaults to cs2-dumper.toml if present.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
     /// The types of files to generate.
     #[arg(
         short,
         long,
+        visible_alias = "formats",
         value_delimiter = ',',
-        default_values = ["cs", "hpp", "json", "rs", "zig"]
+        value_parser = file_type_parser()
     )]
     file_types: Vec<String>,
 
-    /// The number of spaces to use per indentation level.
-    #[arg(short, long, default_value_t = 4)]
-    indent_size: usize,
+    /// The item categories to analyze and generate files for.
+    #[arg(
+        long,
+        value_delimiter = ',',
+        value_parser = PossibleValuesParser::new(analysis::CATEGORIES.iter().copied())
+    )]
+    categories: Vec<String>,
+
+    /// Whether to indent code files with spaces or tabs.
+    #[arg(long, value_enum)]
+    indent_style: Option<IndentKind>,
+
+    /// The number of spaces to use per indentation level. Ignored when indenting with tabs.
+    #[arg(short, long)]
+    indent_size: Option<usize>,
+
+    /// End the lines of the generated files with CRLF. The default on Windows.
+    #[arg(long, conflicts_with = "lf")]
+    crlf: bool,
+
+    /// End the lines of the generated files with LF. The default outside of Windows.
+    #[arg(long)]
+    lf: bool,
+
+    /// Write offset values in code files as hexadecimal literals.
+    #[arg(long, conflicts_with = "decimal")]
+    hex: bool,
+
+    /// Write offset values in code files as decimal literals.
+    #[arg(long)]
+    decimal: bool,
+
+    /// Write the names of offsets, buttons and interfaces in the case convention of constants in
+    /// each language.
+    #[arg(long)]
+    language_case: bool,
+
+    /// The namespace to put the generated code in, with its parts separated by dots (e.g.
+    /// `Acme.CS2`).
+    #[arg(long, value_delimiter = '.')]
+    root_namespace: Vec<String>,
+
+    /// Don't start the generated files with a comment that has the time of the dump.
+    #[arg(long)]
+    no_banner: bool,
+
+    /// Write the schema classes in C# as structs with an explicit layout for interop with
+    /// unmanaged code.
+    #[arg(long)]
+    cs_explicit_layout: bool,
+
+    /// Write an unsafe method in C# that reads the value of each offset with a known type.
+    #[arg(long)]
+    cs_helpers: bool,
 
     /// The output directory to write the generated files to.
-    #[arg(short, long, default_value = "output")]
-    output: PathBuf,
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Write the generated files to a subdirectory of the output directory that is named after the
+    /// time of the dump.
+    #[arg(long)]
+    timestamp_dir: bool,
+
+    /// Write the generated files to stdout instead of the output directory.
+    #[arg(long)]
+    stdout: bool,
+
+    /// Generate the files without writing them, and print the first lines of each file instead.
+    #[arg(long, conflicts_with_all = ["stdout", "zip"])]
+    dry_run: bool,
+
+    /// Read back each written file and check that it matches the generated content.
+    #[arg(long, conflicts_with_all = ["stdout", "zip", "dry_run"])]
+    validate: bool,
+
+    /// The output directory of a previous dump to generate a diff against.
+    #[arg(long)]
+    diff: Option<PathBuf>,
+
+    /// The output directory of a reference dump to check the dump against. Fails if any entry
+    /// of the reference dump changed or was removed.
+    #[arg(long, conflicts_with_all = ["watch", "serve"])]
+    check: Option<PathBuf>,
+
+    /// The compression to apply to the generated files.
+    #[arg(long, value_enum)]
+    compress: Option<Compression>,
+
+    /// Append the build number to the name of each generated file.
+    #[arg(long)]
+    versioned_filenames: bool,
+
+    /// Write the generated files to a dump.zip archive instead of separate files.
+    #[arg(long, conflicts_with_all = ["stdout", "compress"])]
+    zip: bool,
+
+    /// Write a Cargo.toml and lib.rs that make the generated Rust files a crate.
+    #[arg(long)]
+    rust_crate: bool,
+
+    /// Put the generated Python files in a cs2_offsets package with a setup.py.
+    #[arg(long)]
+    python_package: bool,
+
+    /// Put the generated TypeScript files in a cs2-offsets npm package.
+    #[arg(long)]
+    npm_package: bool,
+
+    /// Put the generated Go files in a cs2 package of a Go module.
+    #[arg(long)]
+    go_module: bool,
+
+    /// Write a JSON Schema of the generated JSON files to results.schema.json.
+    #[arg(long)]
+    json_schema: bool,
+
+    /// The directory of previous dumps to merge into the result before it's written.
+    #[arg(long)]
+    merge: Option<PathBuf>,
+
+    /// How to resolve entries that differ between the merged dumps.
+    #[arg(long, value_enum)]
+    merge_strategy: Option<MergeStrategy>,
+
+    /// The maximum number of entries to read from each interface vtable.
+    #[arg(long)]
+    vtable_size: Option<usize>,
+
+    /// Whether to write offsets relative to their module or as absolute addresses.
+    #[arg(long, value_enum)]
+    address_mode: Option<AddressMode>,
+
+    /// The maximum number of attempts to analyze each category when reading memory fails.
+    #[arg(long)]
+    retry_attempts: Option<u8>,
+
+    /// The delay before the first retry in milliseconds, which doubles after each retry.
+    #[arg(long)]
+    retry_delay_ms: Option<u64>,
+
+    /// Fail instead of warning when an offset name is found more than once in a module.
+    #[arg(long)]
+    strict: bool,
+
+    /// Fail instead of warning when an offset name is found in multiple modules with different
+    /// values.
+    #[arg(long)]
+    strict_unique_names: bool,
+
+    /// Analyze the game even if there is a cached result for its build.
+    #[arg(long)]
+    no_cache: bool,
+
+    /// The directory to store cached results in.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+
+    /// The directory to load custom `<category>.<file type>.tera` templates from.
+    #[arg(long)]
+    template_dir: Option<PathBuf>,
+
+    /// A YAML file that maps offsets (e.g. client.dll::dwEntityList) to descriptions, which are
+    /// written as doc comments.
+    #[arg(long)]
+    annotations: Option<PathBuf>,
+
+    /// A regular expression that module names must match to be included in the output.
+    #[arg(long)]
+    module_filter: Option<String>,
+
+    /// A regular expression that button, pointer chain, interface, offset and vtable names must
+    /// match to be included in the output.
+    #[arg(long)]
+    offset_filter: Option<String>,
+
+    /// A regular expression that schema class and enum names must match to be included in the
+    /// output.
+    #[arg(long)]
+    schema_filter: Option<String>,
 
     /// The name of the game process.
     #[arg(short, long, default_value = "cs2.exe")]
@@ -59,26 +266,78 @@ struct Args {
     #[arg(short, long, action = ArgAction::Count)]
     verbose: u8,
 
+    /// The level of the messages to log to the terminal (off, error, warn, info, debug or trace).
+    #[arg(long, conflicts_with = "verbose")]
+    log_level: Option<LevelFilter>,
+
     /// Prevent creation of the cs2-dumper.log file.
     #[arg(short, long)]
     no_log_file: bool,
+
+    /// Keep running and re-dump whenever the build number of the game changes.
+    #[arg(long)]
+    watch: bool,
+
+    /// The number of seconds to wait between build number checks in watch mode.
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+
+    /// Serve the dump as JSON over HTTP on the given address after dumping (e.g. 127.0.0.1:8080).
+    #[arg(long, conflicts_with = "watch")]
+    serve: Option<String>,
+
+    /// Push the changed offsets to WebSocket clients of ws://<addr>/updates after each re-dump in
+    /// watch mode (e.g. 127.0.0.1:8081).
+    #[arg(long, requires = "watch")]
+    ws: Option<String>,
+
+    /// Print the completion script of the given shell to stdout and exit.
+    #[arg(long, value_name = "SHELL")]
+    generate_completion: Option<Shell>,
+
+    /// Write the man page to cs2-dumper.1 in the output directory and exit.
+    #[arg(long)]
+    man_page: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
-    let level_filter = match args.verbose {
+    if let Some(shell) = args.generate_completion {
+        clap_complete::generate(shell, &mut Args::command(), "cs2-dumper", &mut io::stdout());
+
+        return Ok(());
+    }
+
+    let level_filter = args.log_level.unwrap_or(match args.verbose {
         0 => LevelFilter::Error,
         1 => LevelFilter::Warn,
         2 => LevelFilter::Info,
         3 => LevelFilter::Debug,
         _ => LevelFilter::Trace,
+    });
+
+    let config = load_config(&args)?;
+
+    if args.man_page {
+        fs::create_dir_all(&config.out_dir)?;
+
+        let mut file = File::create(config.out_dir.join(man_page::MAN_PAGE_FILE_NAME))?;
+
+        return Ok(man_page::write_man_page(Args::command(), &mut file)?);
+    }
+
+    // Keep stdout clean for the generated files.
+    let terminal_mode = if config.stdout {
+        TerminalMode::Stderr
+    } else {
+        TerminalMode::Mixed
     };
 
     let mut loggers: Vec<Box<dyn SharedLogger>> = vec![TermLogger::new(
         level_filter,
         Config::default(),
-        TerminalMode::Mixed,
+        terminal_mode,
         ColorChoice::Auto,
     )];
 
@@ -98,39 +357,524 @@ fn main() -> Result<()> {
         .map(|s| ConnectorArgs::from_str(&s).expect("unable to parse connector arguments"))
         .unwrap_or_default();
 
-    let mut os = match args.connector {
-        Some(conn) => {
-            let mut inventory = Inventory::scan();
+    // The path is passed as the target directly, since the argument string is split at colons,
+    // which Windows paths contain.
+    let (connector, conn_args) = match (&args.dump_file, args.kvm_pid) {
+        (Some(path), _) => (
+            Some(DUMP_FILE_CONNECTOR.to_string()),
+            ConnectorArgs::new(Some(&path.to_string_lossy()), Default::default(), None),
+        ),
+        (None, Some(pid)) => (
+            Some(KVM_CONNECTOR.to_string()),
+            ConnectorArgs::new(Some(&pid.to_string()), Default::default(), None),
+        ),
+        (None, None) => (args.connector, conn_args),
+    };
+
+    #[cfg(windows)]
+    let mut os = match connector {
+        Some(conn) => connect(&conn, conn_args)?,
+        None => memflow_native::create_os(&OsArgs::default(), LibArc::default())?,
+    };
+
+    // There's no native OS layer outside of Windows, so the game is read from a KVM guest.
+    #[cfg(not(windows))]
+    let mut os = connect(connector.as_deref().unwrap_or(KVM_CONNECTOR), conn_args)?;
+
+    if let Some(pid) = args.kvm_pid
+        && os.process_info_by_name(&args.process_name).is_err()
+    {
+        bail!(
+            "no {} process in the VM with PID {}",
+            args.process_name,
+            pid
+        );
+    }
+
+    // Absolute addresses are only valid until the game restarts, so the build number couldn't be
+    // read anymore after an update.
+    if args.watch && config.address_mode == AddressMode::Absolute {
+        bail!("watch mode requires the rva address mode");
+    }
+
+    // Read the reference dump before any of its files get overwritten.
+    let reference = match &args.check {
+        Some(path) => Some(output::read_json_dump(path, &config.categories)?),
+        None => None,
+    };
+
+    let (mut result, mut build_number, duration) = {
+        let mut process = os.process_by_name(&args.process_name)?;
+
+        let now = Instant::now();
+
+        let result = dump(&mut process, &config)?;
+        let duration = now.elapsed();
+
+        let build_number = analysis::read_build_number_from_process(
+            &mut process,
+            &result.offsets,
+            result.address_mode,
+            Some(analysis::BUILD_NUMBER_MODULE),
+        );
+
+        (result, build_number, duration)
+    };
+
+    if let Some(reference) = &reference {
+        return check(&result, reference, &config);
+    }
+
+    if let Some(addr) = &args.serve {
+        return server::serve(addr, &result, build_number, duration);
+    }
+
+    if !args.watch {
+        return Ok(());
+    }
+
+    let updates = args.ws.as_deref().map(UpdateServer::bind).transpose()?;
+
+    let (tx, rx) = mpsc::channel();
+
+    ctrlc::set_handler(move || {
+        let _ = tx.send(());
+    })?;
+
+    info!(
+        "watching for build number changes every {} seconds (current: {:?})",
+        args.interval, build_number
+    );
 
-            inventory
-                .builder()
-                .connector(&conn)
-                .args(conn_args)
-                .os("win32")
-                .build()?
+    loop {
+        match rx.recv_timeout(Duration::from_secs(args.interval)) {
+            Err(RecvTimeoutError::Timeout) => {}
+            _ => {
+                info!("shutting down");
+
+                return Ok(());
+            }
         }
-        None => {
-            #[cfg(windows)]
-            {
-                memflow_native::create_os(&OsArgs::default(), LibArc::default())?
+
+        let mut process = match os.process_by_name(&args.process_name) {
+            Ok(process) => process,
+            Err(err) => {
+                warn!("failed to find process {}: {}", args.process_name, err);
+
+                continue;
+            }
+        };
+
+        let current_build_number = analysis::read_build_number_from_process(
+            &mut process,
+            &result.offsets,
+            result.address_mode,
+            Some(analysis::BUILD_NUMBER_MODULE),
+        );
+
+        // The game might still be starting up, so wait until the build number can be read.
+        if current_build_number.is_none() || current_build_number == build_number {
+            continue;
+        }
+
+        info!(
+            "build number changed from {:?} to {:?}",
+            build_number, current_build_number
+        );
+
+        match dump(&mut process, &config) {
+            Ok(new_result) => {
+                build_number = analysis::read_build_number_from_process(
+                    &mut process,
+                    &new_result.offsets,
+                    new_result.address_mode,
+                    Some(analysis::BUILD_NUMBER_MODULE),
+                );
+
+                if let Some(updates) = &updates {
+                    updates.push_update(build_number, &new_result.diff(&result));
+                }
+
+                result = new_result;
             }
-            #[cfg(not(windows))]
+            Err(err) => error!("failed to dump: {}", err),
+        }
+    }
+}
+
+/// The memflow connector that reads the memory of a QEMU/KVM virtual machine, which is used
+/// outside of Windows if none is specified.
+const KVM_CONNECTOR: &str = "kvm";
+
+/// Fails if any entry of `reference` changed or was removed in `result`, after writing the changes
+/// to stderr. Added entries don't break code that was written against the reference dump.
+fn check(result: &AnalysisResult, reference: &AnalysisResult, config: &DumpConfig) -> Result<()> {
+    let filtered = result.filtered(config)?;
+    let result = filtered.as_ref().unwrap_or(result);
+
+    let reference = reference
+        .filtered(config)?
+        .unwrap_or_else(|| reference.clone());
+
+    let diff = result.diff(&reference).breaking();
+
+    if diff.is_empty() {
+        info!("no breaking changes since the reference dump");
+
+        return Ok(());
+    }
+
+    let mut stderr = io::stderr().lock();
+
+    for (category, modules) in [
+        ("buttons", &diff.buttons),
+        ("interfaces", &diff.interfaces),
+        ("offsets", &diff.offsets),
+        ("schemas", &diff.schemas),
+    ] {
+        for (module_name, changes) in modules {
+            for (name, change) in changes {
+                match change {
+                    Change::Removed { old } => writeln!(
+                        stderr,
+                        "{} {} {}: removed (was {:#X})",
+                        category, module_name, name, old
+                    )?,
+                    Change::Changed { old, new } => writeln!(
+                        stderr,
+                        "{} {} {}: {:#X} -> {:#X}",
+                        category, module_name, name, old, new
+                    )?,
+                    Change::Added { .. } => {}
+                }
+            }
+        }
+    }
+
+    bail!(
+        "{} entries changed or were removed since the reference dump",
+        diff.len()
+    );
+}
+
+/// The memflow connector that reads memory dump files.
+const DUMP_FILE_CONNECTOR: &str = "coredump";
+
+/// Creates the Windows OS layer on top of a memflow connector, which has to be installed.
+///
+/// To read the game from a QEMU/KVM virtual machine with the `kvm` connector:
+///
+/// 1. Install the connector and the OS plugin with `memflowup install memflow-kvm memflow-win32`.
+/// 2. Load the memflow kernel module on the host with `sudo modprobe memflow`.
+/// 3. Start the Windows guest with KVM acceleration (e.g. `qemu-system-x86_64 -enable-kvm`),
+///    and the game inside of it.
+/// 4. Pass the PID of the QEMU process on the host with `--kvm-pid`, and run the dumper as root
+///    or as a member of the `memflow` group, which has access to `/dev/memflow`.
+fn connect(conn: &str, conn_args: ConnectorArgs) -> Result<OsInstanceArcBox<'static>> {
+    let mut inventory = Inventory::scan();
+
+    let available = inventory.available_connectors();
+
+    if !available.iter().any(|name| name == conn) {
+        bail!(
+            "connector \"{}\" is not installed (available: {})",
+            conn,
+            available.join(", ")
+        );
+    }
+
+    Ok(inventory
+        .builder()
+        .connector(conn)
+        .args(conn_args)
+        .os("win32")
+        .build()?)
+}
+
+/// The resolution of the progress bar, which is driven by fractions rather than a step count.
+const PROGRESS_BAR_LEN: u64 = 1000;
+
+fn dump<P: Process + MemoryView>(process: &mut P, config: &DumpConfig) -> Result<AnalysisResult> {
+    let now = Instant::now();
+
+    let cache = Cache::open(process, config);
+
+    let mut result = match cache.as_ref().and_then(|cache| cache.load()) {
+        Some(result) => {
+            info!("using cached result for the current build");
+
+            result
+        }
+        None => {
+            let (result, complete) = analysis::analyze_all(process, config)?;
+
+            // A failed category is left empty, which shouldn't be reused for the whole build.
+            if complete
+                && let Some(cache) = &cache
+                && let Err(err) = cache.store(&result)
             {
-                panic!("no connector specified")
+                warn!("failed to cache result: {}", err);
             }
+
+            result
         }
     };
 
-    let mut process = os.process_by_name(&args.process_name)?;
+    // The current result is always the newest one, so it's merged last.
+    if let Some(dir) = &config.merge {
+        let dumps = output::read_json_dumps(dir, &config.categories)?;
 
-    let now = Instant::now();
+        info!("merging {} previous dumps", dumps.len());
+
+        let sources: Vec<_> = dumps
+            .iter()
+            .map(|(_, result)| result)
+            .chain([&result])
+            .collect();
+
+        result = AnalysisResult::merge(&sources, config.merge_strategy)?;
+    }
+
+    let output = Output::new(config, &result)?;
 
-    let result = analysis::analyze_all(&mut process)?;
-    let output = Output::new(&args.file_types, args.indent_size, &args.output, &result)?;
+    let bar = ProgressBar::new(PROGRESS_BAR_LEN).with_style(ProgressStyle::with_template(
+        "{bar:40} {percent:>3}% {msg}",
+    )?);
 
-    output.dump_all(&mut process)?;
+    let progress: ProgressCallback = Box::new({
+        let bar = bar.clone();
+
+        move |event| {
+            bar.set_position((event.fraction * PROGRESS_BAR_LEN as f32) as u64);
+            bar.set_message(event.stage);
+        }
+    });
+
+    output.dump_all(process, Some(&progress))?;
+
+    bar.finish_and_clear();
 
     info!("analysis completed in {:.2?}", now.elapsed());
 
-    Ok(())
+    Ok(result)
+}
+
+/// Returns the parser of the file types, which accepts their aliases and replaces them with the
+/// file type they stand for.
+fn file_type_parser() -> impl TypedValueParser<Value = String> {
+    let file_types = output::FILE_TYPES.iter().map(|file_type| {
+        output::FILE_TYPE_ALIASES
+            .iter()
+            .filter(|(_, name)| name == file_type)
+            .fold(PossibleValue::new(*file_type), |value, (alias, _)| {
+                value.alias(*alias)
+            })
+    });
+
+    PossibleValuesParser::new(file_types)
+        .map(|file_type| output::canonical_file_type(&file_type).to_string())
+}
+
+fn load_config(args: &Args) -> Result<DumpConfig> {
+    let mut config = match &args.config {
+        Some(path) => DumpConfig::from_file(path)?,
+        None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+            DumpConfig::from_file(DEFAULT_CONFIG_PATH)?
+        }
+        None => DumpConfig::default(),
+    };
+
+    // Command line arguments take precedence over the configuration file.
+    if !args.file_types.is_empty() {
+        config.file_types = args.file_types.clone();
+    }
+
+    if !args.categories.is_empty() {
+        config.categories = args.categories.clone();
+    }
+
+    if let Some(indent_style) = args.indent_style {
+        config.indent_style = indent_style;
+    }
+
+    if let Some(indent_size) = args.indent_size {
+        config.indent_size = indent_size;
+    }
+
+    if args.crlf {
+        config.line_ending = LineEnding::CrLf;
+    } else if args.lf {
+        config.line_ending = LineEnding::Lf;
+    }
+
+    if let Some(output) = &args.output {
+        config.out_dir = output.clone();
+    }
+
+    if args.timestamp_dir {
+        config.timestamp_dir = true;
+    }
+
+    if args.stdout {
+        config.stdout = true;
+    }
+
+    if args.dry_run {
+        config.dry_run = true;
+    }
+
+    if args.validate {
+        config.validate = true;
+    }
+
+    if let Some(diff) = &args.diff {
+        config.diff = Some(diff.clone());
+    }
+
+    if let Some(compress) = args.compress {
+        config.compress = compress;
+    }
+
+    if args.versioned_filenames {
+        config.versioned_file_names = true;
+    }
+
+    if args.zip {
+        config.zip = true;
+    }
+
+    if args.rust_crate {
+        config.rust_crate = true;
+    }
+
+    if args.python_package {
+        config.python_package = true;
+    }
+
+    if args.npm_package {
+        config.npm_package = true;
+    }
+
+    if args.go_module {
+        config.go_module = true;
+    }
+
+    if args.json_schema {
+        config.json_schema = true;
+    }
+
+    if let Some(merge) = &args.merge {
+        config.merge = Some(merge.clone());
+    }
+
+    if let Some(merge_strategy) = args.merge_strategy {
+        config.merge_strategy = merge_strategy;
+    }
+
+    if let Some(vtable_size) = args.vtable_size {
+        config.vtable_size = vtable_size;
+    }
+
+    if let Some(address_mode) = args.address_mode {
+        config.address_mode = address_mode;
+    }
+
+    if args.hex {
+        config.value_format = ValueFormat::Hex;
+    } else if args.decimal {
+        config.value_format = ValueFormat::Decimal;
+    }
+
+    if args.language_case {
+        config.language_case = true;
+    }
+
+    if !args.root_namespace.is_empty() {
+        config.root_namespace = Some(args.root_namespace.clone());
+    }
+
+    if args.no_banner {
+        config.write_banner = false;
+    }
+
+    if args.cs_explicit_layout {
+        config.cs_explicit_layout = true;
+    }
+
+    if args.cs_helpers {
+        config.cs_helpers = true;
+    }
+
+    if let Some(retry_attempts) = args.retry_attempts {
+        config.retry.max_attempts = retry_attempts;
+    }
+
+    if let Some(retry_delay_ms) = args.retry_delay_ms {
+        config.retry.base_delay_ms = retry_delay_ms;
+    }
+
+    if args.strict {
+        config.strict = true;
+    }
+
+    if args.strict_unique_names {
+        config.strict_unique_names = true;
+    }
+
+    if args.no_cache {
+        config.cache = false;
+    }
+
+    if let Some(cache_dir) = &args.cache_dir {
+        config.cache_dir = Some(cache_dir.clone());
+    }
+
+    if let Some(template_dir) = &args.template_dir {
+        config.template_dir = Some(template_dir.clone());
+    }
+
+    if let Some(annotations) = &args.annotations {
+        config.annotations = Some(annotations.clone());
+    }
+
+    if let Some(module_filter) = &args.module_filter {
+        config.module_filter = Some(module_filter.clone());
+    }
+
+    if let Some(offset_filter) = &args.offset_filter {
+        config.offset_filter = Some(offset_filter.clone());
+    }
+
+    if let Some(schema_filter) = &args.schema_filter {
+        config.schema_filter = Some(schema_filter.clone());
+    }
+
+    config.validate()?;
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// Requires a QEMU/KVM virtual machine whose PID is in `CS2_DUMPER_KVM_PID`.
+    #[test]
+    #[ignore]
+    fn kvm_connector() -> Result<()> {
+        let Ok(pid) = env::var("CS2_DUMPER_KVM_PID") else {
+            return Ok(());
+        };
+
+        let mut os = connect(
+            KVM_CONNECTOR,
+            ConnectorArgs::new(Some(&pid), Default::default(), None),
+        )?;
+
+        os.process_info_by_name("cs2.exe")?;
+
+        Ok(())
+    }
 }
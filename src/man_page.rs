@@ -0,0 +1,144 @@
+use std::io::{self, Write};
+
+use clap::Command;
+
+use clap_mangen::Man;
+use clap_mangen::roff::{Roff, bold, italic, roman};
+
+/// The name of the man page file, in section 1 for user commands.
+pub const MAN_PAGE_FILE_NAME: &str = "cs2-dumper.1";
+
+const EXIT_STATUSES: &[(&str, &str)] = &[
+    ("0", "The dump succeeded."),
+    (
+        "1",
+        "The dump failed, e.g. because the game process wasn't found, a generated file failed \
+         validation, or --check found changed entries.",
+    ),
+    ("2", "The arguments are invalid."),
+];
+
+const FILES: &[(&str, &str)] = &[
+    (
+        "<out-dir>/{buttons,interfaces,offsets,vtables}.<ext>",
+        "The entries of each category in each file type.",
+    ),
+    (
+        "<out-dir>/<module>.<ext>",
+        "The schema classes and enums of each module (e.g. client_dll.hpp).",
+    ),
+    (
+        "<out-dir>/info.json",
+        "The time of the dump, the build number of the game and the number of entries of each \
+         module.",
+    ),
+    (
+        "<out-dir>/CHANGELOG.md",
+        "The changes since the dump given with --diff.",
+    ),
+    (
+        "cs2-dumper.toml",
+        "The configuration file, which is read from the working directory if present.",
+    ),
+    (
+        "cs2-dumper.log",
+        "The log file, unless --no-log-file is given.",
+    ),
+];
+
+const EXAMPLES: &[(&str, &str)] = &[
+    (
+        "cs2-dumper",
+        "Dump the running game to the output directory in the default file types.",
+    ),
+    (
+        "cs2-dumper -f hpp,rs,json -o offsets",
+        "Only write C++, Rust and JSON files, to the offsets directory.",
+    ),
+    (
+        "cs2-dumper --dump-file MEMORY.DMP -vv",
+        "Dump the game from a full memory dump of Windows, with more logging.",
+    ),
+    (
+        "cs2-dumper --watch --interval 60",
+        "Re-dump whenever the build number of the game changes, checking every minute.",
+    ),
+    (
+        "cs2-dumper --check output",
+        "Fail if an entry of the previous dump in the output directory changed or was removed.",
+    ),
+    (
+        "cs2-dumper --generate-completion bash > /etc/bash_completion.d/cs2-dumper",
+        "Install the completions for bash.",
+    ),
+];
+
+/// Writes the man page of `cmd`, with the options generated from its arguments followed by the
+/// exit status, the generated files and examples of common invocations.
+pub fn write_man_page(cmd: Command, out: &mut dyn Write) -> io::Result<()> {
+    let man = Man::new(cmd);
+
+    man.render_title(out)?;
+    man.render_name_section(out)?;
+    man.render_synopsis_section(out)?;
+    man.render_description_section(out)?;
+    man.render_options_section(out)?;
+
+    let mut roff = Roff::new();
+
+    roff.control("SH", ["EXIT STATUS"]);
+
+    for (status, description) in EXIT_STATUSES {
+        roff.control("TP", []);
+        roff.text([bold(*status)]);
+        roff.text([roman(*description)]);
+    }
+
+    roff.control("SH", ["FILES"]);
+
+    for (path, description) in FILES {
+        roff.control("TP", []);
+        roff.text([italic(*path)]);
+        roff.text([roman(*description)]);
+    }
+
+    roff.control("SH", ["EXAMPLES"]);
+
+    for (command, description) in EXAMPLES {
+        roff.control("TP", []);
+        roff.text([bold(*command)]);
+        roff.text([roman(*description)]);
+    }
+
+    roff.to_writer(out)?;
+
+    man.render_version_section(out)?;
+    man.render_authors_section(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Arg;
+
+    use super::*;
+
+    #[test]
+    fn sections() {
+        let cmd = Command::new("cs2-dumper")
+            .version("0.1.3")
+            .arg(Arg::new("watch").long("watch").help("Keep running"));
+
+        let mut out = Vec::new();
+
+        write_man_page(cmd, &mut out).unwrap();
+
+        let page = String::from_utf8(out).unwrap();
+
+        for section in ["OPTIONS", "EXIT STATUS", "FILES", "EXAMPLES", "VERSION"] {
+            assert!(page.contains(section), "{}", page);
+        }
+
+        assert!(page.contains("Keep running"));
+        assert!(page.contains("interval 60"), "{}", page);
+    }
+}
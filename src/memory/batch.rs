@@ -0,0 +1,106 @@
+use std::marker::PhantomData;
+
+use memflow::prelude::v1::*;
+
+/// A handle to a value that is read by [`BatchReader::execute`].
+pub struct BatchHandle<T> {
+    index: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Clone for BatchHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BatchHandle<T> {}
+
+/// Queues reads and issues them all at once, in address order and with adjacent reads merged into
+/// a single one.
+#[derive(Default)]
+pub struct BatchReader {
+    requests: Vec<(Address, usize)>,
+    results: Vec<Vec<u8>>,
+}
+
+impl BatchReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a read of a `T` at `address`.
+    pub fn queue<T: Pod>(&mut self, address: Address) -> BatchHandle<T> {
+        self.requests.push((address, size_of::<T>()));
+
+        BatchHandle {
+            index: self.requests.len() - 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reads all queued values.
+    pub fn execute(&mut self, mem: &mut impl MemoryView) -> Result<()> {
+        let mut order: Vec<_> = (0..self.requests.len()).collect();
+
+        order.sort_by_key(|&index| self.requests[index].0);
+
+        // The start, length and request indices of each contiguous range.
+        let mut spans: Vec<(umem, umem, Vec<usize>)> = Vec::new();
+
+        for index in order {
+            let (address, size) = self.requests[index];
+
+            let start = address.to_umem();
+            let end = start + size as umem;
+
+            match spans.last_mut() {
+                Some((span_start, span_len, indices)) if start <= *span_start + *span_len => {
+                    *span_len = (*span_len).max(end - *span_start);
+
+                    indices.push(index);
+                }
+                _ => spans.push((start, size as umem, vec![index])),
+            }
+        }
+
+        let mut buffers: Vec<_> = spans
+            .iter()
+            .map(|(_, len, _)| vec![0u8; *len as usize])
+            .collect();
+
+        let mut data: Vec<ReadData> = spans
+            .iter()
+            .zip(buffers.iter_mut())
+            .map(|((start, _, _), buf)| CTup2(Address::from(*start), buf.as_mut_slice().into()))
+            .collect();
+
+        mem.read_raw_list(&mut data).data_part()?;
+
+        drop(data);
+
+        self.results = vec![Vec::new(); self.requests.len()];
+
+        for ((start, _, indices), buf) in spans.iter().zip(&buffers) {
+            for &index in indices {
+                let (address, size) = self.requests[index];
+                let offset = (address.to_umem() - start) as usize;
+
+                self.results[index] = buf[offset..offset + size].to_vec();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the value of `handle` after [`execute`](Self::execute) was called.
+    pub fn get<T: Pod>(&self, handle: BatchHandle<T>) -> T {
+        let mut value = T::zeroed();
+
+        value
+            .as_bytes_mut()
+            .copy_from_slice(&self.results[handle.index]);
+
+        value
+    }
+}
@@ -1 +1,3 @@
 pub mod address;
+pub mod batch;
+pub mod retry;
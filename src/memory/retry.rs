@@ -0,0 +1,94 @@
+use std::fmt::Display;
+use std::thread;
+use std::time::Duration;
+
+use log::debug;
+
+use serde::Deserialize;
+
+/// How to retry memory reads that fail transiently, e.g. while the game is loading a map.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetryConfig {
+    /// The maximum number of attempts, including the first one.
+    pub max_attempts: u8,
+
+    /// The delay before the first retry in milliseconds, which doubles after each retry.
+    pub base_delay_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 250,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Calls `f` until it succeeds, backing off exponentially between attempts. The error of the
+    /// last attempt is returned if all of them fail.
+    pub fn retry<T, E: Display>(&self, mut f: impl FnMut() -> Result<T, E>) -> Result<T, E> {
+        let mut attempt = 1;
+
+        loop {
+            match f() {
+                Ok(value) => return Ok(value),
+                Err(err) if attempt >= self.max_attempts => return Err(err),
+                Err(err) => {
+                    let delay = Duration::from_millis(
+                        self.base_delay_ms
+                            .saturating_mul(2u64.saturating_pow(attempt as u32 - 1)),
+                    );
+
+                    debug!(
+                        "attempt {} of {} failed, retrying in {:?}: {}",
+                        attempt, self.max_attempts, delay, err
+                    );
+
+                    thread::sleep(delay);
+
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_until_success() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            base_delay_ms: 0,
+        };
+
+        let mut calls = 0;
+
+        let result = config.retry(|| {
+            calls += 1;
+
+            if calls < 3 {
+                Err("read failed")
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result, Ok(3));
+
+        let mut calls = 0;
+
+        let result: Result<(), _> = config.retry(|| {
+            calls += 1;
+
+            Err(calls)
+        });
+
+        assert_eq!(result, Err(3));
+    }
+}
@@ -0,0 +1,80 @@
+use std::io::{Seek, Write};
+
+use anyhow::Result;
+
+use zip::CompressionMethod;
+use zip::write::{SimpleFileOptions, ZipWriter};
+
+/// A zip archive that the generated files are added to instead of being written to the output
+/// directory.
+pub struct Archive<W: Write + Seek> {
+    writer: ZipWriter<W>,
+}
+
+impl<W: Write + Seek> Archive<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            writer: ZipWriter::new(inner),
+        }
+    }
+
+    /// Adds a file to the directory of its extension (e.g. `rs/offsets.rs`), except for
+    /// `info.json`, which describes the whole archive.
+    pub fn add(&mut self, file_name: &str, content: &[u8]) -> Result<()> {
+        let path = match file_name.rsplit_once('.') {
+            Some((_, ext)) if file_name != "info.json" => format!("{}/{}", ext, file_name),
+            _ => file_name.to_string(),
+        };
+
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        self.writer.start_file(path, options)?;
+        self.writer.write_all(content)?;
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> Result<W> {
+        Ok(self.writer.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use zip::ZipArchive;
+
+    use super::*;
+
+    #[test]
+    fn directories_per_extension() {
+        let mut archive = Archive::new(Cursor::new(Vec::new()));
+
+        archive
+            .add("offsets.rs", b"pub const dwBuildNumber: usize = 0x5A1234;")
+            .unwrap();
+        archive.add("schemas.reclass.xml", b"<ReClass />").unwrap();
+        archive.add("info.json", b"{}").unwrap();
+
+        let mut zip = ZipArchive::new(archive.finish().unwrap()).unwrap();
+
+        let mut names: Vec<_> = zip.file_names().map(|name| name.to_string()).collect();
+
+        names.sort();
+
+        assert_eq!(
+            names,
+            ["info.json", "rs/offsets.rs", "xml/schemas.reclass.xml"]
+        );
+
+        let mut content = String::new();
+
+        zip.by_name("rs/offsets.rs")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+
+        assert_eq!(content, "pub const dwBuildNumber: usize = 0x5A1234;");
+    }
+}
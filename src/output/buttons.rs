@@ -1,16 +1,28 @@
 use std::collections::BTreeMap;
 use std::fmt::{self, Write};
 
-use super::{ButtonMap, CodeWriter, Formatter, zig_ident};
+use serde_json::json;
+
+use super::{
+    ButtonMap, CodeOptions, CodeWriter, Formatter, JAVA_PACKAGE, cpp_ident, cs_ident, java_ident,
+    lua_key, py_ident, rs_ident, toml_key, zig_ident,
+};
 
 impl CodeWriter for ButtonMap {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        fmt.block("namespace CS2Dumper", false, |fmt| {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!("namespace {}", opts.root_namespace("CS2Dumper", "."));
+
+        fmt.block(&namespace, false, |fmt| {
             writeln!(fmt, "// Module: client.dll")?;
 
             fmt.block("public static class Buttons", false, |fmt| {
                 for (name, value) in self {
-                    writeln!(fmt, "public const nint {} = {:#X};", name, value)?;
+                    writeln!(
+                        fmt,
+                        "public const nint {} = {};",
+                        cs_ident(name),
+                        fmt.value(value)
+                    )?;
                 }
 
                 Ok(())
@@ -18,17 +30,42 @@ impl CodeWriter for ButtonMap {
         })
     }
 
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_go(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+        writeln!(fmt, "// Module: client.dll")?;
+
+        fmt.block("type Button struct", false, |fmt| {
+            writeln!(fmt, "Name   string")?;
+            writeln!(fmt, "Offset uintptr")
+        })?;
+
+        fmt.block("var Buttons = []Button", false, |fmt| {
+            for (name, value) in self {
+                writeln!(fmt, "{{\"{}\", {}}},", name, fmt.value(value))?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#pragma once\n")?;
         writeln!(fmt, "#include <cstddef>")?;
         writeln!(fmt, "#include <cstdint>\n")?;
 
-        fmt.block("namespace cs2_dumper", false, |fmt| {
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
             writeln!(fmt, "// Module: client.dll")?;
 
             fmt.block("namespace buttons", false, |fmt| {
                 for (name, value) in self {
-                    writeln!(fmt, "constexpr std::ptrdiff_t {} = {:#X};", name, value)?;
+                    writeln!(
+                        fmt,
+                        "constexpr std::ptrdiff_t {} = {};",
+                        cpp_ident(name),
+                        fmt.value(value)
+                    )?;
                 }
 
                 Ok(())
@@ -36,7 +73,36 @@ impl CodeWriter for ButtonMap {
         })
     }
 
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+        writeln!(fmt, "import java.util.List;\n")?;
+        writeln!(fmt, "// Module: client.dll")?;
+
+        fmt.block("public final class buttons", false, |fmt| {
+            for (name, value) in self {
+                writeln!(
+                    fmt,
+                    "public static final long {} = {}L;",
+                    java_ident(name),
+                    fmt.value(value)
+                )?;
+            }
+
+            let names = self
+                .keys()
+                .map(|name| format!("\"{}\"", name))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            writeln!(
+                fmt,
+                "public static final List<String> NAMES = List.of({});",
+                names
+            )
+        })
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         let content = {
             let buttons: BTreeMap<_, _> = self.iter().map(|(name, value)| (name, value)).collect();
 
@@ -46,35 +112,135 @@ impl CodeWriter for ButtonMap {
         fmt.write_str(&serde_json::to_string_pretty(&content).unwrap())
     }
 
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (name, value) in self {
+            fmt.write_jsonl_record(&json!({
+                "module": "client.dll",
+                "name": name,
+                "value": value,
+            }))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "-- Module: client.dll")?;
+            writeln!(fmt, "buttons = {{")?;
+
+            fmt.indent(|fmt| {
+                for (name, value) in self {
+                    writeln!(fmt, "{} = {},", lua_key(name), fmt.value(value))?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "# Module: client.dll")?;
+        writeln!(fmt, "class buttons:")?;
+
+        fmt.indent(|fmt| {
+            if self.is_empty() {
+                return writeln!(fmt, "pass");
+            }
+
+            for (name, value) in self {
+                writeln!(
+                    fmt,
+                    "{} = {}",
+                    py_ident(&opts.const_name(name)),
+                    fmt.value(value)
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#![allow(non_upper_case_globals, unused)]\n")?;
 
-        fmt.block("pub mod cs2_dumper", false, |fmt| {
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
             writeln!(fmt, "// Module: client.dll")?;
 
             fmt.block("pub mod buttons", false, |fmt| {
                 for (name, value) in self {
-                    let mut name = name.clone();
+                    writeln!(
+                        fmt,
+                        "pub const {}: usize = {};",
+                        rs_ident(&opts.const_name(name)),
+                        fmt.value(value)
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "# Module: client.dll")?;
+        writeln!(fmt, "[{}]", toml_key("client.dll"))?;
 
-                    if name == "use" {
-                        name = format!("r#{}", name);
-                    }
+        for (name, value) in self {
+            writeln!(fmt, "{} = {}", toml_key(name), fmt.value(value))?;
+        }
 
-                    writeln!(fmt, "pub const {}: usize = {:#X};", name, value)?;
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            writeln!(fmt, "// Module: client.dll")?;
+
+            writeln!(fmt, "export const buttons = {{")?;
+
+            fmt.indent(|fmt| {
+                for (name, value) in self {
+                    writeln!(fmt, "{}: {},", name, fmt.value(value))?;
                 }
 
                 Ok(())
-            })
+            })?;
+
+            writeln!(fmt, "}} as const;")
         })
     }
 
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        let content = BTreeMap::from_iter([("client.dll", self)]);
+
+        fmt.write_str(&serde_yaml::to_string(&content).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         fmt.block("pub const cs2_dumper = struct", true, |fmt| {
             writeln!(fmt, "// Module: client.dll")?;
 
             fmt.block("pub const buttons = struct", true, |fmt| {
                 for (name, value) in self {
-                    writeln!(fmt, "pub const {}: usize = {:#X};", zig_ident(name), value)?;
+                    writeln!(
+                        fmt,
+                        "pub const {}: usize = {};",
+                        zig_ident(name),
+                        fmt.value(value)
+                    )?;
                 }
 
                 Ok(())
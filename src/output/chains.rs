@@ -0,0 +1,425 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use heck::{AsPascalCase, AsSnakeCase};
+
+use serde_json::json;
+
+use super::{
+    ChainMap, CodeOptions, CodeWriter, Formatter, JAVA_PACKAGE, PointerChain, go_ident, java_ident,
+    lua_key, py_ident, slugify, toml_key, ts_ident, zig_ident,
+};
+
+impl PointerChain {
+    fn hex_offsets(&self) -> Vec<String> {
+        self.offsets
+            .iter()
+            .map(|offset| format!("{:#X}", offset))
+            .collect()
+    }
+}
+
+impl CodeWriter for ChainMap {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!("namespace {}.Chains", opts.root_namespace("CS2Dumper", "."));
+
+        fmt.block(&namespace, false, |fmt| {
+            for (module_name, chains) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static class {}", AsPascalCase(slugify(module_name))),
+                    false,
+                    |fmt| {
+                        for (name, chain) in chains {
+                            writeln!(
+                                fmt,
+                                "public static readonly nint[] {} = {{ {} }};",
+                                name,
+                                chain.hex_offsets().join(", ")
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_go(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+
+        for (module_name, chains) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+
+            let prefix = AsPascalCase(slugify(module_name)).to_string();
+
+            for (name, chain) in chains {
+                writeln!(
+                    fmt,
+                    "var {}_{} = [...]uintptr{{{}}}",
+                    prefix,
+                    go_ident(name),
+                    chain.hex_offsets().join(", ")
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "#pragma once\n")?;
+        writeln!(fmt, "#include <cstddef>")?;
+        writeln!(fmt, "#include <cstdint>\n")?;
+
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("namespace chains", false, |fmt| {
+                for (module_name, chains) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, chain) in chains {
+                                // `read` returns the pointer that is stored at the given address.
+                                writeln!(fmt, "template <typename Read>")?;
+
+                                fmt.block(
+                                    &format!(
+                                        "std::uintptr_t {}(std::uintptr_t base, Read&& read)",
+                                        name
+                                    ),
+                                    false,
+                                    |fmt| writeln!(fmt, "return {};", hpp_chain(chain)),
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+
+        fmt.block("public final class chains", false, |fmt| {
+            for (module_name, chains) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static final class {}", slugify(module_name)),
+                    false,
+                    |fmt| {
+                        for (name, chain) in chains {
+                            let offsets = chain
+                                .hex_offsets()
+                                .iter()
+                                .map(|offset| format!("{}L", offset))
+                                .collect::<Vec<_>>()
+                                .join(", ");
+
+                            writeln!(
+                                fmt,
+                                "public static final long[] {} = {{ {} }};",
+                                java_ident(name),
+                                offsets
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        let content: BTreeMap<_, _> = self
+            .iter()
+            .map(|(module_name, chains)| {
+                let chains: BTreeMap<_, _> = chains
+                    .iter()
+                    .map(|(name, chain)| (name, json!({ "chain": chain })))
+                    .collect();
+
+                (module_name, chains)
+            })
+            .collect();
+
+        fmt.write_str(&serde_json::to_string_pretty(&content).unwrap())
+    }
+
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, chains) in self {
+            for (name, chain) in chains {
+                fmt.write_jsonl_record(&json!({
+                    "module": module_name,
+                    "name": name,
+                    "chain": chain,
+                }))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "chains = {{")?;
+
+            fmt.indent(|fmt| {
+                for (module_name, chains) in self {
+                    writeln!(fmt, "-- Module: {}", module_name)?;
+
+                    let module_name = lua_key(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    writeln!(fmt, "{} = {{", module_name)?;
+
+                    fmt.indent(|fmt| {
+                        for (name, chain) in chains {
+                            writeln!(
+                                fmt,
+                                "{} = {{ {} }},",
+                                lua_key(name),
+                                chain.hex_offsets().join(", ")
+                            )?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "}},")?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, chains) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "class {}:", AsSnakeCase(slugify(module_name)))?;
+
+            fmt.indent(|fmt| {
+                if chains.is_empty() {
+                    return writeln!(fmt, "pass");
+                }
+
+                for (name, chain) in chains {
+                    writeln!(
+                        fmt,
+                        "{} = [{}]",
+                        py_ident(name),
+                        chain.hex_offsets().join(", ")
+                    )?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(
+            fmt,
+            "#![allow(non_upper_case_globals, non_snake_case, unused)]\n"
+        )?;
+
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
+            fmt.block("pub mod chains", false, |fmt| {
+                writeln!(fmt, "use memflow::prelude::v1::*;\n")?;
+
+                for (module_name, chains) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("pub mod {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            writeln!(fmt, "use super::*;\n")?;
+
+                            for (name, chain) in chains {
+                                fmt.block(
+                                    &format!(
+                                        "pub fn {}(mem: &mut impl MemoryView, base: Address) -> PartialResult<Address>",
+                                        name
+                                    ),
+                                    false,
+                                    |fmt| write_rs_chain(fmt, chain),
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, chains) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "[{}]", toml_key(module_name))?;
+
+            for (name, chain) in chains {
+                writeln!(
+                    fmt,
+                    "{} = [{}]",
+                    toml_key(name),
+                    chain.hex_offsets().join(", ")
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("export namespace chains", false, |fmt| {
+                for (module_name, chains) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("export namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, chain) in chains {
+                                writeln!(
+                                    fmt,
+                                    "export const {}: readonly number[] = [{}];",
+                                    ts_ident(name),
+                                    chain.hex_offsets().join(", ")
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_yaml::to_string(self).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.block("pub const cs2_dumper = struct", true, |fmt| {
+            fmt.block("pub const chains = struct", true, |fmt| {
+                for (module_name, chains) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    let module_name = zig_ident(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    fmt.block(
+                        &format!("pub const {} = struct", module_name),
+                        true,
+                        |fmt| {
+                            for (name, chain) in chains {
+                                writeln!(
+                                    fmt,
+                                    "pub const {} = [_]usize{{ {} }};",
+                                    zig_ident(name),
+                                    chain.hex_offsets().join(", ")
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+}
+
+/// Builds the nested `read` calls that follow `chain` from `base`.
+fn hpp_chain(chain: &PointerChain) -> String {
+    let offsets = chain.hex_offsets();
+
+    let Some((last, offsets)) = offsets.split_last() else {
+        return "base".to_string();
+    };
+
+    let address = offsets.iter().fold("base".to_string(), |address, offset| {
+        format!("read({} + {})", address, offset)
+    });
+
+    format!("{} + {}", address, last)
+}
+
+fn write_rs_chain(fmt: &mut Formatter<'_>, chain: &PointerChain) -> fmt::Result {
+    let offsets = chain.hex_offsets();
+
+    let Some((last, offsets)) = offsets.split_last() else {
+        return writeln!(fmt, "Ok(base)");
+    };
+
+    if offsets.is_empty() {
+        return writeln!(fmt, "Ok(base + {} as umem)", last);
+    }
+
+    writeln!(fmt, "let mut address = base;\n")?;
+
+    for offset in offsets {
+        writeln!(
+            fmt,
+            "address = mem.read_addr64(address + {} as umem)?;",
+            offset
+        )?;
+    }
+
+    writeln!(fmt, "\nOk(address + {} as umem)", last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hpp_nested_reads() {
+        let chain = PointerChain {
+            offsets: vec![0x18, 0x40, 0x8],
+        };
+
+        assert_eq!(hpp_chain(&chain), "read(read(base + 0x18) + 0x40) + 0x8");
+    }
+}
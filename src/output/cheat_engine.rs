@@ -0,0 +1,117 @@
+use std::fmt::{self, Write};
+
+use super::{AddressMode, Formatter, OffsetMap, OffsetType, offset_type};
+
+/// Writes a Cheat Engine table with a group per module and an entry per offset.
+pub fn write_ct(
+    offsets: &OffsetMap,
+    address_mode: AddressMode,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    writeln!(fmt, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+    writeln!(fmt, "<CheatTable CheatEngineTableVersion=\"45\">")?;
+
+    let mut id = 0;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "<CheatEntries>")?;
+
+        fmt.indent(|fmt| {
+            for (module_name, offsets) in offsets {
+                writeln!(fmt, "<CheatEntry>")?;
+
+                fmt.indent(|fmt| {
+                    writeln!(fmt, "<ID>{}</ID>", id)?;
+                    writeln!(fmt, "<Description>\"{}\"</Description>", module_name)?;
+                    writeln!(fmt, "<GroupHeader>1</GroupHeader>")?;
+                    writeln!(fmt, "<CheatEntries>")?;
+
+                    id += 1;
+
+                    fmt.indent(|fmt| {
+                        for (name, value) in offsets {
+                            // Cheat Engine reads addresses as hexadecimal without a prefix.
+                            let address = match address_mode {
+                                AddressMode::Absolute => format!("{:X}", value),
+                                AddressMode::Rva => format!("{}+{:X}", module_name, value),
+                            };
+
+                            writeln!(fmt, "<CheatEntry>")?;
+
+                            fmt.indent(|fmt| {
+                                let (variable_type, show_as_hex) = variable_type(name);
+
+                                writeln!(fmt, "<ID>{}</ID>", id)?;
+                                writeln!(fmt, "<Description>\"{}\"</Description>", name)?;
+
+                                if show_as_hex {
+                                    writeln!(fmt, "<ShowAsHex>1</ShowAsHex>")?;
+                                }
+
+                                writeln!(fmt, "<VariableType>{}</VariableType>", variable_type)?;
+                                writeln!(fmt, "<Address>{}</Address>", address)
+                            })?;
+
+                            writeln!(fmt, "</CheatEntry>")?;
+
+                            id += 1;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "</CheatEntries>")
+                })?;
+
+                writeln!(fmt, "</CheatEntry>")?;
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt, "</CheatEntries>")
+    })?;
+
+    writeln!(fmt, "</CheatTable>")
+}
+
+/// Returns the Cheat Engine variable type of an offset and whether its value is shown as hex.
+/// Offsets of an unknown type are most likely pointers.
+fn variable_type(name: &str) -> (&'static str, bool) {
+    match offset_type(name) {
+        Some(OffsetType::U8) => ("Byte", false),
+        Some(OffsetType::U16) => ("2 Bytes", false),
+        Some(OffsetType::U32) => ("4 Bytes", false),
+        Some(OffsetType::U64) => ("8 Bytes", false),
+        Some(OffsetType::Ptr) | None => ("8 Bytes", true),
+        Some(OffsetType::Bool) => ("Byte", false),
+        Some(OffsetType::Float) => ("Float", false),
+        Some(OffsetType::Double) => ("Double", false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn ct_entries() {
+        let offsets = OffsetMap::from([(
+            "engine2.dll".to_string(),
+            BTreeMap::from([("dwBuildNumber".to_string(), 0x5A1234)]),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(2), ValueFormat::Hex);
+
+        write_ct(&offsets, AddressMode::Rva, &mut fmt).unwrap();
+
+        assert!(out.contains(
+            "<ID>1</ID>\n          <Description>\"dwBuildNumber\"</Description>\n          <VariableType>4 Bytes</VariableType>\n          <Address>engine2.dll+5A1234</Address>\n"
+        ));
+        assert!(out.ends_with("</CheatTable>\n"));
+    }
+}
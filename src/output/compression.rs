@@ -0,0 +1,66 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+
+use flate2::write::GzEncoder;
+
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Returns the extension that is appended to compressed file names.
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Gzip => Some("gz"),
+            Self::Zstd => Some("zst"),
+        }
+    }
+
+    pub fn compress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            Self::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            Self::Zstd => zstd::encode_all(data, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let data = b"public const nint dwBuildNumber = 0x5A1234;\n";
+
+        let mut gzip = Vec::new();
+
+        GzDecoder::new(&Compression::Gzip.compress(data).unwrap()[..])
+            .read_to_end(&mut gzip)
+            .unwrap();
+
+        let zstd = zstd::decode_all(&Compression::Zstd.compress(data).unwrap()[..]).unwrap();
+
+        assert_eq!(gzip, data);
+        assert_eq!(zstd, data);
+        assert_eq!(Compression::None.compress(data).unwrap(), data);
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use csv::Writer;
+
+use memflow::prelude::v1::*;
+
+/// Returns the entries of an offset or interface map as CSV, with a row per entry in the order of
+/// their module and name.
+pub fn to_csv(modules: &BTreeMap<String, BTreeMap<String, umem>>) -> Result<String> {
+    let mut writer = Writer::from_writer(Vec::new());
+
+    writer.write_record(["module", "name", "value_hex", "value_dec"])?;
+
+    for (module_name, entries) in modules {
+        for (name, value) in entries {
+            writer.write_record([
+                module_name,
+                name,
+                &format!("{:#X}", value),
+                &value.to_string(),
+            ])?;
+        }
+    }
+
+    let content = writer.into_inner().map_err(|err| err.into_error())?;
+
+    Ok(String::from_utf8(content)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_rows() {
+        let offsets = BTreeMap::from([
+            (
+                "engine2.dll".to_string(),
+                BTreeMap::from([("dwBuildNumber".to_string(), 0x5A1234)]),
+            ),
+            (
+                "client.dll".to_string(),
+                BTreeMap::from([
+                    ("dwViewMatrix".to_string(), 0x232EAC0),
+                    ("dwEntityList".to_string(), 0x24CED50),
+                ]),
+            ),
+        ]);
+
+        assert_eq!(
+            to_csv(&offsets).unwrap(),
+            "module,name,value_hex,value_dec\n\
+             client.dll,dwEntityList,0x24CED50,38595920\n\
+             client.dll,dwViewMatrix,0x232EAC0,36891328\n\
+             engine2.dll,dwBuildNumber,0x5A1234,5902900\n"
+        );
+    }
+}
@@ -0,0 +1,578 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use chrono::{DateTime, Utc};
+
+use heck::AsTitleCase;
+
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+use serde_json::{Value, json};
+
+use super::{CodeOptions, CodeWriter, Formatter, signed_hex, slugify, toml_key};
+
+use crate::analysis::{AnalysisResult, Change, Class, ClassField, DiffMap, DumpDiff};
+
+/// The file name suffix of the x64dbg databases in the output directory.
+const X64DBG_FILE_SUFFIX: &str = "_x64dbg";
+
+/// Files in the output directory that don't contain schemas.
+const NON_SCHEMA_FILES: &[&str] = &[
+    "buttons",
+    "chains",
+    "diff",
+    "info",
+    "interfaces",
+    "offsets",
+    "package",
+    "results.schema",
+    "tsconfig",
+    "vtables",
+];
+
+#[derive(Deserialize)]
+struct InfoFile {
+    timestamp: DateTime<Utc>,
+    #[serde(default)]
+    build_number: Option<u32>,
+    #[serde(default)]
+    modules: BTreeMap<String, IgnoredAny>,
+}
+
+#[derive(Deserialize)]
+struct SchemaFile {
+    classes: BTreeMap<String, ClassFile>,
+}
+
+#[derive(Deserialize)]
+struct ClassFile {
+    parent: Option<String>,
+    #[serde(default)]
+    size: i32,
+    #[serde(default)]
+    alignment: u8,
+    fields: BTreeMap<String, i32>,
+}
+
+/// Reads the JSON files of a previous dump from `dir`.
+///
+/// Only the data that is needed to compute a [`DumpDiff`] is restored. Files with versioned names
+/// are found by the build number in `info.json`.
+pub fn read_json_dump(dir: &Path, categories: &[String]) -> Result<AnalysisResult> {
+    let is_enabled = |category: &str| categories.iter().any(|c| c == category);
+
+    let info_path = dir.join("info.json");
+
+    let info: Option<InfoFile> = if info_path.is_file() {
+        Some(read_json(&info_path)?)
+    } else {
+        None
+    };
+
+    let build_number = info.as_ref().and_then(|info| info.build_number);
+
+    let mut result = AnalysisResult::default();
+
+    if is_enabled("buttons") {
+        let mut buttons: BTreeMap<String, _> =
+            read_json(&json_file_path(dir, "buttons", build_number))?;
+
+        result.buttons = buttons.remove("client.dll").unwrap_or_default();
+    }
+
+    if is_enabled("interfaces") {
+        result.interfaces = read_json(&json_file_path(dir, "interfaces", build_number))?;
+    }
+
+    if is_enabled("offsets") {
+        result.offsets = read_json(&json_file_path(dir, "offsets", build_number))?;
+    }
+
+    if is_enabled("schemas") {
+        for path in schema_file_paths(dir, info.as_ref())? {
+            let modules: BTreeMap<String, SchemaFile> = read_json(&path)?;
+
+            for (module_name, schema) in modules {
+                let classes = schema
+                    .classes
+                    .into_iter()
+                    .map(|(name, class)| Class {
+                        name,
+                        module_name: module_name.clone(),
+                        parent_name: class.parent,
+                        size: class.size,
+                        alignment: class.alignment,
+                        metadata: Vec::new(),
+                        fields: class
+                            .fields
+                            .into_iter()
+                            .map(|(name, offset)| ClassField {
+                                name,
+                                type_name: String::new(),
+                                offset,
+                                alignment: None,
+                            })
+                            .collect(),
+                    })
+                    .collect();
+
+                result.schemas.insert(module_name, (classes, Vec::new()));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the paths of the schema files in `dir`.
+///
+/// The files are found by the names of the modules in `info.json`, so that other JSON files in the
+/// output directory aren't mistaken for schemas. Older dumps without module names are scanned for
+/// every JSON file that isn't a known non-schema file instead.
+fn schema_file_paths(dir: &Path, info: Option<&InfoFile>) -> Result<Vec<PathBuf>> {
+    let build_number = info.and_then(|info| info.build_number);
+
+    if let Some(info) = info.filter(|info| !info.modules.is_empty()) {
+        return Ok(info
+            .modules
+            .keys()
+            .map(|module_name| json_file_path(dir, &slugify(module_name), build_number))
+            .filter(|path| path.is_file())
+            .collect());
+    }
+
+    let mut paths = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        let is_schema_file = path.extension().is_some_and(|ext| ext == "json")
+            && path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(|stem| unversioned_stem(stem, build_number))
+                .is_some_and(|stem| {
+                    !NON_SCHEMA_FILES.contains(&stem.as_str())
+                        && !stem.ends_with(X64DBG_FILE_SUFFIX)
+                });
+
+        if is_schema_file {
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Returns the path of the JSON file with `stem` in `dir`, or of the one with the build number
+/// appended if the dump was written with versioned file names.
+fn json_file_path(dir: &Path, stem: &str, build_number: Option<u32>) -> PathBuf {
+    if let Some(build_number) = build_number {
+        let path = dir.join(format!("{}_{}.json", stem, build_number));
+
+        if path.is_file() {
+            return path;
+        }
+    }
+
+    dir.join(format!("{}.json", stem))
+}
+
+/// Returns `stem` without the build number that versioned file names append to the part before
+/// the first dot (e.g. `results_14041.schema` becomes `results.schema`).
+fn unversioned_stem(stem: &str, build_number: Option<u32>) -> String {
+    let Some(build_number) = build_number else {
+        return stem.to_string();
+    };
+
+    let suffix = format!("_{}", build_number);
+
+    match stem.split_once('.') {
+        Some((name, rest)) => format!("{}.{}", name.strip_suffix(&suffix).unwrap_or(name), rest),
+        None => stem.strip_suffix(&suffix).unwrap_or(stem).to_string(),
+    }
+}
+
+/// Reads all previous dumps in `dir` (including `dir` itself) together with their timestamps,
+/// ordered from oldest to newest.
+///
+/// A directory is considered a dump if it contains an `info.json` file.
+pub fn read_json_dumps(
+    dir: &Path,
+    categories: &[String],
+) -> Result<Vec<(DateTime<Utc>, AnalysisResult)>> {
+    let mut dirs = vec![dir.to_path_buf()];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+
+    let mut dumps = Vec::new();
+
+    for dir in dirs {
+        let info_path = dir.join("info.json");
+
+        if !info_path.is_file() {
+            continue;
+        }
+
+        let info: InfoFile = read_json(&info_path)?;
+
+        dumps.push((info.timestamp, read_json_dump(&dir, categories)?));
+    }
+
+    dumps.sort_by_key(|(timestamp, _)| *timestamp);
+
+    Ok(dumps)
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T> {
+    let content = fs::read_to_string(path)?;
+
+    Ok(serde_json::from_str(&content)?)
+}
+
+impl DumpDiff {
+    fn categories(&self) -> [(&str, &DiffMap); 4] {
+        [
+            ("buttons", &self.buttons),
+            ("interfaces", &self.interfaces),
+            ("offsets", &self.offsets),
+            ("schemas", &self.schemas),
+        ]
+    }
+
+    /// Returns the changes as a Markdown document with a table per kind of change and category.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Changelog\n");
+
+        if self.is_empty() {
+            out.push_str("\nNo changes.\n");
+
+            return out;
+        }
+
+        let sections: [(&str, fn(&Change) -> Option<String>); 3] = [
+            ("Added", |change| match change {
+                Change::Added { new } => Some(format!("`{}`", signed_hex(*new))),
+                _ => None,
+            }),
+            ("Removed", |change| match change {
+                Change::Removed { old } => Some(format!("`{}`", signed_hex(*old))),
+                _ => None,
+            }),
+            ("Changed", |change| match change {
+                Change::Changed { old, new } => {
+                    Some(format!("`{}` | `{}`", signed_hex(*old), signed_hex(*new)))
+                }
+                _ => None,
+            }),
+        ];
+
+        for (section, values) in sections {
+            let tables: Vec<_> = self
+                .categories()
+                .into_iter()
+                .filter_map(|(category, modules)| {
+                    let rows: Vec<_> = modules
+                        .iter()
+                        .flat_map(|(module_name, changes)| {
+                            changes.iter().filter_map(move |(name, change)| {
+                                values(change).map(|values| {
+                                    format!("| {} | `{}` | {} |", module_name, name, values)
+                                })
+                            })
+                        })
+                        .collect();
+
+                    (!rows.is_empty()).then_some((category, rows))
+                })
+                .collect();
+
+            if tables.is_empty() {
+                continue;
+            }
+
+            out.push_str(&format!("\n## {}\n", section));
+
+            let header = if section == "Changed" {
+                "| Module | Name | Old | New |\n| --- | --- | --- | --- |"
+            } else {
+                "| Module | Name | Value |\n| --- | --- | --- |"
+            };
+
+            for (category, rows) in tables {
+                out.push_str(&format!("\n### {}\n\n{}\n", AsTitleCase(category), header));
+
+                for row in rows {
+                    out.push_str(&row);
+                    out.push('\n');
+                }
+            }
+        }
+
+        out
+    }
+
+    fn write_report(&self, fmt: &mut Formatter<'_>, comment: &str) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(fmt, "{} No changes", comment);
+        }
+
+        for (category, modules) in self.categories() {
+            if modules.is_empty() {
+                continue;
+            }
+
+            writeln!(fmt, "{} Category: {}", comment, category)?;
+
+            for (module_name, changes) in modules {
+                writeln!(fmt, "{} Module: {}", comment, module_name)?;
+
+                for (name, change) in changes {
+                    match change {
+                        Change::Added { new } => {
+                            writeln!(fmt, "{}   + {} = {}", comment, name, signed_hex(*new))?
+                        }
+                        Change::Removed { old } => {
+                            writeln!(fmt, "{}   - {} = {}", comment, name, signed_hex(*old))?
+                        }
+                        Change::Changed { old, new } => writeln!(
+                            fmt,
+                            "{}   ~ {} = {} -> {}",
+                            comment,
+                            name,
+                            signed_hex(*old),
+                            signed_hex(*new)
+                        )?,
+                    }
+                }
+            }
+
+            writeln!(fmt)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CodeWriter for DumpDiff {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_go(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_java(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_json::to_string_pretty(self).unwrap())
+    }
+
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (category, modules) in self.categories() {
+            for (module_name, changes) in modules {
+                for (name, change) in changes {
+                    let mut record = json!({
+                        "category": category,
+                        "module": module_name,
+                        "name": name,
+                    });
+
+                    // The change is tagged with its kind, so its fields can be merged in.
+                    if let (Value::Object(record), Value::Object(change)) =
+                        (&mut record, json!(change))
+                    {
+                        record.extend(change);
+                    }
+
+                    fmt.write_jsonl_record(&record)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "--")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "#")
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (category, modules) in self.categories() {
+            for (module_name, changes) in modules {
+                writeln!(fmt, "[{}.{}]", category, toml_key(module_name))?;
+
+                for (name, change) in changes {
+                    let value = match change {
+                        Change::Added { new } => format!("{{ kind = \"added\", new = {} }}", new),
+                        Change::Removed { old } => {
+                            format!("{{ kind = \"removed\", old = {} }}", old)
+                        }
+                        Change::Changed { old, new } => {
+                            format!("{{ kind = \"changed\", old = {}, new = {} }}", old, new)
+                        }
+                    };
+
+                    writeln!(fmt, "{} = {}", toml_key(name), value)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_yaml::to_string(self).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        self.write_report(fmt, "//")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::{env, process};
+
+    use super::*;
+
+    use crate::output::{category_json, module_stats};
+
+    #[test]
+    fn markdown_changelog() {
+        let diff = DumpDiff {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([
+                    ("dwAdded".to_string(), Change::Added { new: 0x40 }),
+                    (
+                        "dwChanged".to_string(),
+                        Change::Changed {
+                            old: 0x10,
+                            new: 0x18,
+                        },
+                    ),
+                ]),
+            )]),
+            ..Default::default()
+        };
+
+        let markdown = diff.to_markdown();
+
+        assert!(markdown.contains("## Added\n\n### Offsets\n"));
+        assert!(markdown.contains("| client.dll | `dwAdded` | `0x40` |"));
+        assert!(markdown.contains("| client.dll | `dwChanged` | `0x10` | `0x18` |"));
+        assert!(!markdown.contains("## Removed"));
+    }
+
+    #[test]
+    fn read_versioned_json_dump() {
+        let dir = env::temp_dir().join(format!("cs2-dumper-versioned-{}", process::id()));
+
+        fs::create_dir_all(&dir).unwrap();
+
+        let result = AnalysisResult {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+            )]),
+            schemas: BTreeMap::from([(
+                "client.dll".to_string(),
+                (
+                    vec![Class {
+                        name: "C_BaseEntity".to_string(),
+                        module_name: "client.dll".to_string(),
+                        parent_name: None,
+                        size: 0x10,
+                        alignment: 8,
+                        metadata: Vec::new(),
+                        fields: vec![ClassField {
+                            name: "m_iHealth".to_string(),
+                            type_name: "int32".to_string(),
+                            offset: 0x8,
+                            alignment: None,
+                        }],
+                    }],
+                    Vec::new(),
+                ),
+            )]),
+            ..Default::default()
+        };
+
+        let write = |file_name: &str, content: Value| {
+            fs::write(dir.join(file_name), content.to_string()).unwrap();
+        };
+
+        write(
+            "offsets_14041.json",
+            category_json(&result, "offsets").unwrap(),
+        );
+        write(
+            "client_dll_14041.json",
+            category_json(&result, "schemas").unwrap(),
+        );
+
+        let categories = ["offsets".to_string(), "schemas".to_string()];
+
+        // Older dumps don't list their modules, so the directory is scanned for schema files.
+        let mut reads = Vec::new();
+
+        for info in [
+            json!({
+                "timestamp": "2024-01-01T00:00:00Z",
+                "build_number": 14041,
+                "modules": module_stats(&result),
+            }),
+            json!({ "timestamp": "2024-01-01T00:00:00Z", "build_number": 14041 }),
+        ] {
+            write("info.json", info);
+
+            reads.push(read_json_dump(&dir, &categories));
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+
+        for read in reads {
+            let read = read.unwrap();
+
+            assert_eq!(read.offsets, result.offsets);
+            assert_eq!(read.schemas.len(), 1);
+
+            let fields = &read.schemas["client.dll"].0[0].fields;
+
+            assert_eq!(fields[0].name, "m_iHealth");
+            assert_eq!(fields[0].offset, 0x8);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::fmt::{self, Write};
+
+use flatbuffers::{FlatBufferBuilder, TableFinishedWIPOffset, VOffsetT, WIPOffset};
+
+use super::{AnalysisResult, Formatter};
+
+/// The identifier of the binary files, which readers can check before accessing the root table.
+const FILE_IDENTIFIER: &str = "CS2D";
+
+type Table = WIPOffset<TableFinishedWIPOffset>;
+
+/// Writes a FlatBuffers schema of the binary files written by [`to_fbs_binary`].
+///
+/// The tables have the same fields as the messages of the Protobuf definitions, except for the
+/// classes and enums of each module, since a binary file only holds a single dump.
+pub fn write_fbs(namespace: &str, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "namespace {};\n", namespace)?;
+
+    write_table(fmt, "Button", &[("name", "string"), ("value", "ulong")])?;
+
+    for table_name in ["Interface", "Offset"] {
+        write_table(
+            fmt,
+            table_name,
+            &[("module", "string"), ("name", "string"), ("value", "ulong")],
+        )?;
+    }
+
+    write_table(
+        fmt,
+        "SchemaField",
+        &[
+            ("name", "string"),
+            ("type_name", "string"),
+            ("offset", "int"),
+        ],
+    )?;
+
+    write_table(
+        fmt,
+        "SchemaClass",
+        &[
+            ("module", "string"),
+            ("name", "string"),
+            ("parent", "string"),
+            ("size", "int"),
+            ("fields", "[SchemaField]"),
+        ],
+    )?;
+
+    // A build number of 0 means that it couldn't be read.
+    write_table(
+        fmt,
+        "DumpResult",
+        &[
+            ("build_number", "uint"),
+            ("buttons", "[Button]"),
+            ("interfaces", "[Interface]"),
+            ("offsets", "[Offset]"),
+            ("schemas", "[SchemaClass]"),
+        ],
+    )?;
+
+    writeln!(fmt, "root_type DumpResult;")?;
+    writeln!(fmt, "file_identifier \"{}\";", FILE_IDENTIFIER)?;
+    writeln!(fmt, "file_extension \"bin\";")
+}
+
+fn write_table(fmt: &mut Formatter<'_>, name: &str, fields: &[(&str, &str)]) -> fmt::Result {
+    fmt.block(&format!("table {}", name), false, |fmt| {
+        for (field_name, type_name) in fields {
+            writeln!(fmt, "{}: {};", field_name, type_name)?;
+        }
+
+        Ok(())
+    })?;
+
+    writeln!(fmt)
+}
+
+/// Returns a `DumpResult` with the buttons, interfaces, offsets and schemas of the categories in
+/// `categories`, serialized with the schema written by [`write_fbs`].
+pub fn to_fbs_binary(
+    result: &AnalysisResult,
+    categories: &[String],
+    build_number: Option<u32>,
+) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+
+    let is_enabled = |category: &str| categories.iter().any(|c| c == category);
+
+    let mut buttons = Vec::new();
+
+    if is_enabled("buttons") {
+        for (name, value) in &result.buttons {
+            buttons.push(value_table(&mut builder, None, name, *value));
+        }
+    }
+
+    let mut interfaces = Vec::new();
+
+    if is_enabled("interfaces") {
+        for (module_name, ifaces) in &result.interfaces {
+            for (name, value) in ifaces {
+                interfaces.push(value_table(&mut builder, Some(module_name), name, *value));
+            }
+        }
+    }
+
+    let mut offsets = Vec::new();
+
+    if is_enabled("offsets") {
+        for (module_name, module_offsets) in &result.offsets {
+            for (name, value) in module_offsets {
+                offsets.push(value_table(&mut builder, Some(module_name), name, *value));
+            }
+        }
+    }
+
+    let mut classes = Vec::new();
+
+    if is_enabled("schemas") {
+        for (module_name, (module_classes, _)) in &result.schemas {
+            for class in module_classes {
+                let fields: Vec<_> = class
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let name = builder.create_string(&field.name);
+                        let type_name = builder.create_string(&field.type_name);
+
+                        let start = builder.start_table();
+
+                        builder.push_slot_always(slot(0), name);
+                        builder.push_slot_always(slot(1), type_name);
+                        builder.push_slot(slot(2), field.offset, 0);
+
+                        builder.end_table(start)
+                    })
+                    .collect();
+
+                let fields = builder.create_vector(&fields);
+                let module = builder.create_string(module_name);
+                let name = builder.create_string(&class.name);
+
+                let parent = class
+                    .parent_name
+                    .as_ref()
+                    .map(|parent_name| builder.create_string(parent_name));
+
+                let start = builder.start_table();
+
+                builder.push_slot_always(slot(0), module);
+                builder.push_slot_always(slot(1), name);
+
+                if let Some(parent) = parent {
+                    builder.push_slot_always(slot(2), parent);
+                }
+
+                builder.push_slot(slot(3), class.size, 0);
+                builder.push_slot_always(slot(4), fields);
+
+                classes.push(builder.end_table(start));
+            }
+        }
+    }
+
+    let buttons = builder.create_vector(&buttons);
+    let interfaces = builder.create_vector(&interfaces);
+    let offsets = builder.create_vector(&offsets);
+    let classes = builder.create_vector(&classes);
+
+    let start = builder.start_table();
+
+    builder.push_slot(slot(0), build_number.unwrap_or(0), 0);
+    builder.push_slot_always(slot(1), buttons);
+    builder.push_slot_always(slot(2), interfaces);
+    builder.push_slot_always(slot(3), offsets);
+    builder.push_slot_always(slot(4), classes);
+
+    let root = builder.end_table(start);
+
+    builder.finish(root, Some(FILE_IDENTIFIER));
+
+    builder.finished_data().to_vec()
+}
+
+/// Returns the vtable offset of the field with the given index in its table.
+#[inline]
+const fn slot(index: VOffsetT) -> VOffsetT {
+    4 + index * 2
+}
+
+/// Adds a `Button`, or an `Interface` or `Offset` if it has a module.
+fn value_table(
+    builder: &mut FlatBufferBuilder<'_>,
+    module_name: Option<&str>,
+    name: &str,
+    value: u64,
+) -> Table {
+    let module = module_name.map(|module_name| builder.create_string(module_name));
+    let name = builder.create_string(name);
+
+    let start = builder.start_table();
+    let mut index = 0;
+
+    if let Some(module) = module {
+        builder.push_slot_always(slot(index), module);
+
+        index += 1;
+    }
+
+    builder.push_slot_always(slot(index), name);
+    builder.push_slot(slot(index + 1), value, 0);
+
+    builder.end_table(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn fbs_binary() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_fbs("cs2_dumper", &mut fmt).unwrap();
+
+        assert!(out.starts_with("namespace cs2_dumper;\n"));
+        assert!(out.contains(
+            "table Offset {\n    module: string;\n    name: string;\n    value: ulong;\n}\n"
+        ));
+        assert!(out.ends_with(
+            "root_type DumpResult;\nfile_identifier \"CS2D\";\nfile_extension \"bin\";\n"
+        ));
+
+        let result = AnalysisResult {
+            offsets: [(
+                "client.dll".to_string(),
+                [("dwEntityList".to_string(), 0x1A1F730)].into(),
+            )]
+            .into(),
+            ..Default::default()
+        };
+
+        let data = to_fbs_binary(&result, &["offsets".to_string()], Some(14041));
+
+        assert!(flatbuffers::buffer_has_identifier(
+            &data,
+            FILE_IDENTIFIER,
+            false
+        ));
+        assert!(data.windows(12).any(|window| window == b"dwEntityList"));
+    }
+}
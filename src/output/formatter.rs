@@ -1,20 +1,177 @@
+use std::borrow::Cow;
 use std::fmt::{self, Write};
 
+use clap::ValueEnum;
+
+use serde::{Deserialize, Serialize};
+
+use serde_json::Value;
+
+/// How offset values are written in code files. JSON and YAML files always use plain integers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueFormat {
+    #[default]
+    Hex,
+    Decimal,
+}
+
+/// Whether code files are indented with spaces or tabs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum IndentKind {
+    #[default]
+    Spaces,
+    Tabs,
+}
+
+/// How each indentation level is written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// The given number of spaces per level.
+    Spaces(usize),
+
+    /// A tab per level, which Go and Makefiles use.
+    Tabs,
+}
+
+impl IndentStyle {
+    /// Returns the style of an indent kind, where `size` is only used for spaces.
+    pub fn new(kind: IndentKind, size: usize) -> Self {
+        match kind {
+            IndentKind::Spaces => Self::Spaces(size),
+            IndentKind::Tabs => Self::Tabs,
+        }
+    }
+}
+
+/// The line ending of generated text files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the line ending of the platform, which is what editors like Notepad expect.
+    pub fn native() -> Self {
+        if cfg!(target_os = "windows") {
+            Self::CrLf
+        } else {
+            Self::Lf
+        }
+    }
+
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+
+    /// Returns `text` with every line ending replaced by this one, for files that aren't written
+    /// through a [`Formatter`] (e.g. the JSON and Markdown files). Line endings that already match
+    /// are kept.
+    pub fn normalize(self, text: &str) -> Cow<'_, str> {
+        let text = if text.contains("\r\n") {
+            Cow::Owned(text.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(text)
+        };
+
+        match self {
+            Self::Lf => text,
+            Self::CrLf => Cow::Owned(text.replace('\n', "\r\n")),
+        }
+    }
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        Self::native()
+    }
+}
+
+/// How the names of constants are cased in code files.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CaseConvention {
+    /// Keep the names as they are in the game, e.g. `dwEntityList`.
+    #[default]
+    Preserve,
+
+    /// `DwEntityList`, which Go requires for exported names.
+    Pascal,
+
+    /// `DW_ENTITY_LIST`, which Python and Rust use for constants.
+    ScreamingSnake,
+}
+
+impl CaseConvention {
+    /// Returns the convention for constants of a file type.
+    pub fn of_file_type(file_type: &str) -> Self {
+        match file_type {
+            "go" => Self::Pascal,
+            "py" | "rs" => Self::ScreamingSnake,
+            _ => Self::Preserve,
+        }
+    }
+}
+
+/// A value that is displayed in a [`ValueFormat`].
+pub struct FormattedValue<T> {
+    value: T,
+    format: ValueFormat,
+}
+
+impl<T: fmt::Display + fmt::UpperHex> fmt::Display for FormattedValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            ValueFormat::Hex => write!(f, "{:#X}", self.value),
+            ValueFormat::Decimal => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// Writes generated code to a string, indenting every line that is written through its [`Write`]
+/// implementation (e.g. with `writeln!`) by the current indentation level.
 pub struct Formatter<'a> {
     out: &'a mut String,
-    indent_size: usize,
+    indent_style: IndentStyle,
     indent_level: usize,
+    value_format: ValueFormat,
+    line_ending: LineEnding,
 }
 
 impl<'a> Formatter<'a> {
-    pub fn new(out: &'a mut String, indent_size: usize) -> Self {
+    /// Creates a formatter that appends to `out`, indenting each level in `indent_style` and writing
+    /// offset values in `value_format`.
+    pub fn new(out: &'a mut String, indent_style: IndentStyle, value_format: ValueFormat) -> Self {
         Self {
             out,
-            indent_size,
+            indent_style,
             indent_level: 0,
+            value_format,
+            line_ending: LineEnding::Lf,
         }
     }
 
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    /// Wraps an offset value so that it's displayed in the configured format.
+    #[inline]
+    pub fn value<T>(&self, value: T) -> FormattedValue<T> {
+        FormattedValue {
+            value,
+            format: self.value_format,
+        }
+    }
+
+    /// Writes `heading` followed by an opening brace, the output of `f` indented by one level, and
+    /// a closing brace with a trailing semicolon if `semicolon` is set.
     // TODO: Refactor this.
     pub fn block<F>(&mut self, heading: &str, semicolon: bool, f: F) -> fmt::Result
     where
@@ -29,6 +186,7 @@ impl<'a> Formatter<'a> {
         Ok(())
     }
 
+    /// Writes the output of `f` indented by one more level.
     pub fn indent<F>(&mut self, f: F) -> fmt::Result
     where
         F: FnOnce(&mut Self) -> fmt::Result,
@@ -42,10 +200,54 @@ impl<'a> Formatter<'a> {
         Ok(())
     }
 
+    /// Writes the output of `f` indented by one less level, e.g. for the access specifiers of a C++
+    /// class. It has no effect at the top level.
+    pub fn dedent<F>(&mut self, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result,
+    {
+        let indent_level = self.indent_level;
+
+        self.indent_level = indent_level.saturating_sub(1);
+
+        f(self)?;
+
+        self.indent_level = indent_level;
+
+        Ok(())
+    }
+
+    /// Writes a single line at the current indentation level.
+    #[inline]
+    pub fn write_line(&mut self, line: &str) -> fmt::Result {
+        writeln!(self, "{}", line)
+    }
+
+    /// Writes each line of `text` as a line comment that starts with `prefix` (e.g. `//` or `#`).
+    pub fn write_comment(&mut self, prefix: &str, text: &str) -> fmt::Result {
+        for line in text.lines() {
+            if line.is_empty() {
+                writeln!(self, "{}", prefix)?;
+            } else {
+                writeln!(self, "{} {}", prefix, line)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a record of a JSON Lines file, which is a JSON object on a single line.
+    pub fn write_jsonl_record(&mut self, record: &Value) -> fmt::Result {
+        writeln!(self, "{}", record)
+    }
+
     #[inline]
     fn push_indentation(&mut self) {
         if self.indent_level > 0 {
-            let indentation = " ".repeat(self.indent_level * self.indent_size);
+            let indentation = match self.indent_style {
+                IndentStyle::Spaces(size) => " ".repeat(self.indent_level * size),
+                IndentStyle::Tabs => "\t".repeat(self.indent_level),
+            };
 
             self.out.push_str(&indentation);
         }
@@ -64,10 +266,99 @@ impl<'a> Write for Formatter<'a> {
             self.out.push_str(line);
 
             if lines.peek().is_some() || s.ends_with('\n') {
-                self.out.push('\n');
+                self.out.push_str(self.line_ending.as_str());
             }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indentation() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        fmt.write_comment("//", "Generated\n\nBuild: 14041")
+            .unwrap();
+
+        fmt.block("class C_BaseEntity", true, |fmt| {
+            fmt.dedent(|fmt| fmt.write_line("public:"))?;
+            fmt.write_line("int32_t m_iHealth;")
+        })
+        .unwrap();
+
+        assert_eq!(
+            out,
+            "// Generated\n//\n// Build: 14041\nclass C_BaseEntity {\npublic:\n    int32_t m_iHealth;\n};\n"
+        );
+    }
+
+    #[test]
+    fn tab_indentation() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(
+            &mut out,
+            IndentStyle::new(IndentKind::Tabs, 4),
+            ValueFormat::Hex,
+        );
+
+        fmt.block("namespace cs2_dumper", false, |fmt| {
+            fmt.block("namespace offsets", false, |fmt| {
+                fmt.write_line("constexpr std::ptrdiff_t dwEntityList = 0x24CED50;")
+            })
+        })
+        .unwrap();
+
+        assert_eq!(
+            out,
+            "namespace cs2_dumper {\n\tnamespace offsets {\n\t\tconstexpr std::ptrdiff_t dwEntityList = 0x24CED50;\n\t}\n}\n"
+        );
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        fmt.set_line_ending(LineEnding::CrLf);
+
+        fmt.write_comment("//", "Generated\n\nBuild: 14041")
+            .unwrap();
+
+        fmt.block("namespace offsets", false, |fmt| {
+            writeln!(fmt, "constexpr std::ptrdiff_t dwEntityList = 0x24CED50;")
+        })
+        .unwrap();
+
+        assert_eq!(
+            out,
+            "// Generated\r\n//\r\n// Build: 14041\r\nnamespace offsets {\r\n    constexpr std::ptrdiff_t dwEntityList = 0x24CED50;\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn normalize_line_endings() {
+        let info =
+            serde_json::to_string_pretty(&serde_json::json!({ "build_number": 14041 })).unwrap();
+
+        assert_eq!(
+            LineEnding::CrLf.normalize(&info),
+            "{\r\n  \"build_number\": 14041\r\n}"
+        );
+
+        // The content of a formatter already has the line ending.
+        assert_eq!(
+            LineEnding::CrLf.normalize("// Generated\r\nnamespace offsets {}\n"),
+            "// Generated\r\nnamespace offsets {}\r\n"
+        );
+        assert_eq!(
+            LineEnding::Lf.normalize("# Changelog\r\n\nNo changes.\n"),
+            "# Changelog\n\nNo changes.\n"
+        );
+    }
+}
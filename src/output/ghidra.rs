@@ -0,0 +1,183 @@
+use std::fmt::{self, Write};
+
+use heck::AsSnakeCase;
+
+use super::{AddressMode, Formatter, OffsetMap, SchemaMap, slugify};
+
+/// Writes a Ghidra Python script that labels the offsets of the module that is loaded in the
+/// current program.
+pub fn write_offsets(
+    offsets: &OffsetMap,
+    address_mode: AddressMode,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    writeln!(fmt, "# @category CS2Dumper\n")?;
+    writeln!(fmt, "from ghidra.program.model.symbol import SourceType\n")?;
+
+    for (module_name, offsets) in offsets {
+        writeln!(fmt, "# Module: {}", module_name)?;
+        writeln!(fmt, "def {}(base):", module_fn("label", module_name))?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "symbol_table = currentProgram.getSymbolTable()\n")?;
+
+            for (name, value) in offsets {
+                writeln!(
+                    fmt,
+                    "symbol_table.createLabel(toAddr(base + {}), \"{}\", SourceType.USER_DEFINED)",
+                    fmt.value(value),
+                    name
+                )?;
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt)?;
+    }
+
+    write_modules(fmt, offsets.keys(), "label")?;
+
+    writeln!(fmt, "def main():")?;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "module_name = currentProgram.getName()\n")?;
+
+        writeln!(fmt, "if module_name not in MODULES:")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "print(\"No offsets for \" + module_name)")?;
+            writeln!(fmt, "return")
+        })?;
+
+        writeln!(fmt)?;
+
+        match address_mode {
+            // Absolute addresses already include the base address of the module.
+            AddressMode::Absolute => writeln!(fmt, "MODULES[module_name](0)"),
+            AddressMode::Rva => writeln!(
+                fmt,
+                "MODULES[module_name](currentProgram.getImageBase().getOffset())"
+            ),
+        }
+    })?;
+
+    write_main_guard(fmt)
+}
+
+/// Writes a Ghidra Python script that adds the schema classes of the module that is loaded in the
+/// current program as structures to its data type manager.
+///
+/// The field types are only known by name, so each field is added as an undefined byte that is
+/// commented with its type.
+pub fn write_schemas(schemas: &SchemaMap, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "# @category CS2Dumper\n")?;
+
+    writeln!(
+        fmt,
+        "from ghidra.program.model.data import CategoryPath, DataTypeConflictHandler, StructureDataType, Undefined1DataType\n"
+    )?;
+
+    for (module_name, (classes, _)) in schemas {
+        writeln!(fmt, "# Module: {}", module_name)?;
+        writeln!(fmt, "def {}(dtm):", module_fn("add", module_name))?;
+
+        fmt.indent(|fmt| {
+            writeln!(
+                fmt,
+                "category = CategoryPath(\"/cs2_dumper/{}\")\n",
+                slugify(module_name)
+            )?;
+
+            for class in classes {
+                writeln!(
+                    fmt,
+                    "structure = StructureDataType(category, \"{}\", {:#X})",
+                    slugify(&class.name),
+                    class.size.max(0)
+                )?;
+
+                // Fields past the end of the class can't be added to its structure.
+                for field in class
+                    .fields
+                    .iter()
+                    .filter(|field| field.offset >= 0 && field.offset < class.size)
+                {
+                    writeln!(
+                        fmt,
+                        "structure.replaceAtOffset({}, Undefined1DataType.dataType, 1, \"{}\", \"{}\")",
+                        fmt.value(field.offset),
+                        field.name,
+                        field.type_name
+                    )?;
+                }
+
+                writeln!(
+                    fmt,
+                    "dtm.addDataType(structure, DataTypeConflictHandler.REPLACE_HANDLER)"
+                )?;
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt)?;
+    }
+
+    write_modules(fmt, schemas.keys(), "add")?;
+
+    writeln!(fmt, "def main():")?;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "module_name = currentProgram.getName()\n")?;
+
+        writeln!(fmt, "if module_name not in MODULES:")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "print(\"No schemas for \" + module_name)")?;
+            writeln!(fmt, "return")
+        })?;
+
+        writeln!(fmt)?;
+        writeln!(
+            fmt,
+            "MODULES[module_name](currentProgram.getDataTypeManager())"
+        )
+    })?;
+
+    write_main_guard(fmt)
+}
+
+fn write_modules<'a>(
+    fmt: &mut Formatter<'_>,
+    module_names: impl Iterator<Item = &'a String>,
+    prefix: &str,
+) -> fmt::Result {
+    writeln!(fmt, "MODULES = {{")?;
+
+    fmt.indent(|fmt| {
+        for module_name in module_names {
+            writeln!(
+                fmt,
+                "\"{}\": {},",
+                module_name,
+                module_fn(prefix, module_name)
+            )?;
+        }
+
+        Ok(())
+    })?;
+
+    writeln!(fmt, "}}\n")
+}
+
+fn write_main_guard(fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt)?;
+    writeln!(fmt, "if __name__ == \"__main__\":")?;
+
+    fmt.indent(|fmt| writeln!(fmt, "main()"))
+}
+
+fn module_fn(prefix: &str, module_name: &str) -> String {
+    format!("{}_{}", prefix, AsSnakeCase(slugify(module_name)))
+}
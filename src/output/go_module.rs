@@ -0,0 +1,35 @@
+use std::fmt::{self, Write};
+
+use super::Formatter;
+
+/// The path of the generated module.
+const MODULE_PATH: &str = "github.com/cs2dumper/offsets";
+
+/// The Go version of the module. The generated code only declares constants, variables and types,
+/// so it builds with any toolchain since this version.
+const GO_VERSION: &str = "1.18";
+
+/// The directory of the `cs2` package within the module.
+pub const PACKAGE_DIR: &str = "cs2";
+
+/// Writes the `go.mod` file of the module.
+pub fn write_go_mod(fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "module {}\n", MODULE_PATH)?;
+    writeln!(fmt, "go {}", GO_VERSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn go_mod() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_go_mod(&mut fmt).unwrap();
+
+        assert_eq!(out, "module github.com/cs2dumper/offsets\n\ngo 1.18\n");
+    }
+}
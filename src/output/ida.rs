@@ -0,0 +1,120 @@
+use std::fmt::{self, Write};
+
+use heck::AsSnakeCase;
+
+use super::{AddressMode, Formatter, OffsetMap, slugify};
+
+/// Writes an IDAPython script that labels the offsets of the module that is loaded in the IDA
+/// database.
+pub fn write_idc(
+    offsets: &OffsetMap,
+    address_mode: AddressMode,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    writeln!(fmt, "import idaapi")?;
+    writeln!(fmt, "import idc\n")?;
+
+    for (module_name, offsets) in offsets {
+        writeln!(fmt, "# Module: {}", module_name)?;
+        writeln!(fmt, "def {}(base):", label_fn(module_name))?;
+
+        fmt.indent(|fmt| {
+            if offsets.is_empty() {
+                return writeln!(fmt, "pass");
+            }
+
+            for (name, value) in offsets {
+                writeln!(
+                    fmt,
+                    "idc.set_name(base + {}, \"{}\", idc.SN_NOWARN)",
+                    fmt.value(value),
+                    name
+                )?;
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt)?;
+    }
+
+    writeln!(fmt, "MODULES = {{")?;
+
+    fmt.indent(|fmt| {
+        for module_name in offsets.keys() {
+            writeln!(fmt, "\"{}\": {},", module_name, label_fn(module_name))?;
+        }
+
+        Ok(())
+    })?;
+
+    writeln!(fmt, "}}\n")?;
+
+    writeln!(fmt, "def main():")?;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "module_name = idaapi.get_root_filename()\n")?;
+
+        writeln!(fmt, "if module_name not in MODULES:")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "print(\"No offsets for \" + module_name)")?;
+            writeln!(fmt, "return")
+        })?;
+
+        writeln!(fmt)?;
+
+        match address_mode {
+            // Absolute addresses already include the base address of the module.
+            AddressMode::Absolute => writeln!(fmt, "base = 0")?,
+            AddressMode::Rva => {
+                writeln!(
+                    fmt,
+                    "base = idc.ask_addr(idaapi.get_imagebase(), \"Base address of \" + module_name)\n"
+                )?;
+
+                writeln!(fmt, "if base is None:")?;
+
+                fmt.indent(|fmt| writeln!(fmt, "return"))?;
+            }
+        }
+
+        writeln!(fmt)?;
+        writeln!(fmt, "MODULES[module_name](base)")
+    })?;
+
+    writeln!(fmt)?;
+    writeln!(fmt, "if __name__ == \"__main__\":")?;
+
+    fmt.indent(|fmt| writeln!(fmt, "main()"))
+}
+
+fn label_fn(module_name: &str) -> String {
+    format!("label_{}", AsSnakeCase(slugify(module_name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn idc_labels() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_idc(&offsets, AddressMode::Rva, &mut fmt).unwrap();
+
+        assert!(out.contains(
+            "def label_client_dll(base):\n    idc.set_name(base + 0x24CED50, \"dwEntityList\", idc.SN_NOWARN)\n"
+        ));
+        assert!(out.contains("\"client.dll\": label_client_dll,"));
+    }
+}
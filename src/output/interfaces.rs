@@ -3,11 +3,21 @@ use std::fmt::{self, Write};
 
 use heck::{AsPascalCase, AsSnakeCase};
 
-use super::{CodeWriter, Formatter, InterfaceMap, slugify, zig_ident};
+use serde_json::json;
+
+use super::{
+    CodeOptions, CodeWriter, Formatter, InterfaceMap, JAVA_PACKAGE, cpp_ident, cs_ident,
+    java_ident, lua_key, py_ident, rs_ident, slugify, toml_key, ts_ident, zig_ident,
+};
 
 impl CodeWriter for InterfaceMap {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        fmt.block("namespace CS2Dumper.Interfaces", false, |fmt| {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "namespace {}.Interfaces",
+            opts.root_namespace("CS2Dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
             for (module_name, ifaces) in self {
                 writeln!(fmt, "// Module: {}", module_name)?;
 
@@ -19,11 +29,17 @@ impl CodeWriter for InterfaceMap {
                             if *value > i32::MAX as u64 {
                                 writeln!(
                                     fmt,
-                                    "public static readonly nint {} = unchecked((nint){:#X});",
-                                    name, value
+                                    "public static readonly nint {} = unchecked((nint){});",
+                                    cs_ident(name),
+                                    fmt.value(value)
                                 )?;
                             } else {
-                                writeln!(fmt, "public const nint {} = {:#X};", name, value)?;
+                                writeln!(
+                                    fmt,
+                                    "public const nint {} = {};",
+                                    cs_ident(name),
+                                    fmt.value(value)
+                                )?;
                             };
                         }
 
@@ -36,12 +52,44 @@ impl CodeWriter for InterfaceMap {
         })
     }
 
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_go(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+
+        for (module_name, ifaces) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+
+            let prefix = AsPascalCase(slugify(module_name)).to_string();
+
+            writeln!(fmt, "const (")?;
+
+            fmt.indent(|fmt| {
+                for (name, value) in ifaces {
+                    writeln!(
+                        fmt,
+                        "{}_{} = {}",
+                        prefix,
+                        opts.const_name(name),
+                        fmt.value(value)
+                    )?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, ")")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#pragma once\n")?;
         writeln!(fmt, "#include <cstddef>")?;
         writeln!(fmt, "#include <cstdint>\n")?;
 
-        fmt.block("namespace cs2_dumper", false, |fmt| {
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
             fmt.block("namespace interfaces", false, |fmt| {
                 for (module_name, ifaces) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -51,7 +99,12 @@ impl CodeWriter for InterfaceMap {
                         false,
                         |fmt| {
                             for (name, value) in ifaces {
-                                writeln!(fmt, "constexpr std::ptrdiff_t {} = {:#X};", name, value)?;
+                                writeln!(
+                                    fmt,
+                                    "constexpr std::ptrdiff_t {} = {};",
+                                    cpp_ident(name),
+                                    fmt.value(value)
+                                )?;
                             }
 
                             Ok(())
@@ -64,7 +117,36 @@ impl CodeWriter for InterfaceMap {
         })
     }
 
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+
+        fmt.block("public final class interfaces", false, |fmt| {
+            for (module_name, ifaces) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static final class {}", slugify(module_name)),
+                    false,
+                    |fmt| {
+                        for (name, value) in ifaces {
+                            writeln!(
+                                fmt,
+                                "public static final long {} = {}L;",
+                                java_ident(name),
+                                fmt.value(value)
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         let content: BTreeMap<_, _> = self
             .iter()
             .map(|(module_name, ifaces)| {
@@ -78,10 +160,85 @@ impl CodeWriter for InterfaceMap {
         fmt.write_str(&serde_json::to_string_pretty(&content).unwrap())
     }
 
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, ifaces) in self {
+            for (name, value) in ifaces {
+                fmt.write_jsonl_record(&json!({
+                    "module": module_name,
+                    "name": name,
+                    "value": value,
+                }))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "interfaces = {{")?;
+
+            fmt.indent(|fmt| {
+                for (module_name, ifaces) in self {
+                    writeln!(fmt, "-- Module: {}", module_name)?;
+
+                    let module_name = lua_key(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    writeln!(fmt, "{} = {{", module_name)?;
+
+                    fmt.indent(|fmt| {
+                        for (name, value) in ifaces {
+                            writeln!(fmt, "{} = {},", lua_key(name), fmt.value(value))?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "}},")?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, ifaces) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "class {}:", AsSnakeCase(slugify(module_name)))?;
+
+            fmt.indent(|fmt| {
+                if ifaces.is_empty() {
+                    return writeln!(fmt, "pass");
+                }
+
+                for (name, value) in ifaces {
+                    writeln!(
+                        fmt,
+                        "{} = {}",
+                        py_ident(&opts.const_name(name)),
+                        fmt.value(value)
+                    )?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#![allow(non_upper_case_globals, unused)]\n")?;
 
-        fmt.block("pub mod cs2_dumper", false, |fmt| {
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
             fmt.block("pub mod interfaces", false, |fmt| {
                 for (module_name, ifaces) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -91,7 +248,59 @@ impl CodeWriter for InterfaceMap {
                         false,
                         |fmt| {
                             for (name, value) in ifaces {
-                                writeln!(fmt, "pub const {}: usize = {:#X};", name, value)?;
+                                writeln!(
+                                    fmt,
+                                    "pub const {}: usize = {};",
+                                    rs_ident(&opts.const_name(name)),
+                                    fmt.value(value)
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, ifaces) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "[{}]", toml_key(module_name))?;
+
+            for (name, value) in ifaces {
+                writeln!(fmt, "{} = {}", toml_key(name), fmt.value(value))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("export namespace interfaces", false, |fmt| {
+                for (module_name, ifaces) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("export namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, value) in ifaces {
+                                writeln!(
+                                    fmt,
+                                    "export const {} = {};",
+                                    ts_ident(name),
+                                    fmt.value(value)
+                                )?;
                             }
 
                             Ok(())
@@ -104,7 +313,11 @@ impl CodeWriter for InterfaceMap {
         })
     }
 
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_yaml::to_string(self).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         fmt.block("pub const cs2_dumper = struct", true, |fmt| {
             fmt.block("pub const interfaces = struct", true, |fmt| {
                 for (module_name, ifaces) in self {
@@ -119,9 +332,9 @@ impl CodeWriter for InterfaceMap {
                             for (name, value) in ifaces {
                                 writeln!(
                                     fmt,
-                                    "pub const {}: usize = {:#X};",
+                                    "pub const {}: usize = {};",
                                     zig_ident(name),
-                                    value
+                                    fmt.value(value)
                                 )?;
                             }
 
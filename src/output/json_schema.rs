@@ -0,0 +1,210 @@
+use serde_json::{Value, json};
+
+/// Returns a JSON Schema (draft 2020-12) of the generated JSON files.
+///
+/// The root describes the `/dump` endpoint of the server, which has the content of each category
+/// file and `info.json` in a property of the same name. Each file can be validated against its
+/// definition in `$defs` on its own (e.g. `#/$defs/offsets`).
+///
+/// The schema is written by hand, since the JSON files aren't serialized from the analysis types.
+pub fn results_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "https://github.com/a2x/cs2-dumper/results.schema.json",
+        "title": "cs2-dumper results",
+        "type": "object",
+        "properties": {
+            "buttons": { "$ref": "#/$defs/buttons" },
+            "chains": { "$ref": "#/$defs/chains" },
+            "interfaces": { "$ref": "#/$defs/interfaces" },
+            "offsets": { "$ref": "#/$defs/offsets" },
+            "schemas": { "$ref": "#/$defs/schemas" },
+            "vtables": { "$ref": "#/$defs/vtables" },
+            "info": { "$ref": "#/$defs/info" }
+        },
+        "$defs": {
+            "value": {
+                "description": "An offset or address.",
+                "type": "integer",
+                "minimum": 0
+            },
+            "value_map": {
+                "description": "The values of a module by name.",
+                "type": "object",
+                "additionalProperties": { "$ref": "#/$defs/value" }
+            },
+            "modules": {
+                "description": "The values of each module by module name (e.g. client.dll).",
+                "type": "object",
+                "additionalProperties": { "$ref": "#/$defs/value_map" }
+            },
+            "buttons": {
+                "type": "object",
+                "properties": {
+                    "client.dll": { "$ref": "#/$defs/value_map" }
+                },
+                "additionalProperties": false
+            },
+            "chains": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "object",
+                        "properties": {
+                            "chain": {
+                                "type": "array",
+                                "items": { "$ref": "#/$defs/value" }
+                            }
+                        },
+                        "required": ["chain"]
+                    }
+                }
+            },
+            "interfaces": { "$ref": "#/$defs/modules" },
+            "offsets": { "$ref": "#/$defs/modules" },
+            "schemas": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": {
+                        "classes": {
+                            "type": "object",
+                            "additionalProperties": { "$ref": "#/$defs/class" }
+                        },
+                        "enums": {
+                            "type": "object",
+                            "additionalProperties": { "$ref": "#/$defs/enum" }
+                        }
+                    },
+                    "required": ["classes", "enums"]
+                }
+            },
+            "class": {
+                "type": "object",
+                "properties": {
+                    "parent": { "type": ["string", "null"] },
+                    "size": { "type": "integer" },
+                    "alignment": { "type": "integer" },
+                    "fields": { "$ref": "#/$defs/value_map" },
+                    "field_alignments": { "$ref": "#/$defs/value_map" },
+                    "field_union_groups": { "$ref": "#/$defs/value_map" },
+                    "field_type_params": {
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "array",
+                            "items": { "type": "string" }
+                        }
+                    },
+                    "field_array_counts": { "$ref": "#/$defs/value_map" },
+                    "metadata": {
+                        "type": "array",
+                        "items": { "$ref": "#/$defs/metadata" }
+                    }
+                },
+                "required": ["parent", "fields"]
+            },
+            "metadata": {
+                "type": "object",
+                "properties": {
+                    "type": {
+                        "enum": ["NetworkChangeCallback", "NetworkVarNames", "Unknown"]
+                    },
+                    "name": { "type": "string" },
+                    "type_name": { "type": "string" }
+                },
+                "required": ["type", "name"]
+            },
+            "enum": {
+                "type": "object",
+                "properties": {
+                    "alignment": { "type": "integer" },
+                    "type": {
+                        "enum": ["uint8", "uint16", "uint32", "uint64", "unknown"]
+                    },
+                    "members": {
+                        "type": "object",
+                        "additionalProperties": { "type": "integer" }
+                    }
+                },
+                "required": ["alignment", "type", "members"]
+            },
+            "vtables": {
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "object",
+                        "properties": {
+                            "vtable": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "properties": {
+                                        "index": { "type": "integer", "minimum": 0 },
+                                        "address": { "$ref": "#/$defs/value" }
+                                    },
+                                    "required": ["index", "address"]
+                                }
+                            }
+                        },
+                        "required": ["vtable"]
+                    }
+                }
+            },
+            "info": {
+                "type": "object",
+                "properties": {
+                    "timestamp": { "type": "string", "format": "date-time" },
+                    "build_number": { "type": ["integer", "null"] },
+                    "address_mode": { "enum": ["rva", "absolute"] },
+                    "format": { "enum": ["hex", "decimal"] },
+                    "modules": {
+                        "description": "The number of entries of each module by module name.",
+                        "type": "object",
+                        "additionalProperties": {
+                            "type": "object",
+                            "properties": {
+                                "offset_count": { "type": "integer", "minimum": 0 },
+                                "schema_class_count": { "type": "integer", "minimum": 0 },
+                                "interface_count": { "type": "integer", "minimum": 0 }
+                            },
+                            "required": ["offset_count", "schema_class_count", "interface_count"]
+                        }
+                    }
+                },
+                "required": ["timestamp", "build_number"]
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::CATEGORIES;
+
+    #[test]
+    fn category_definitions() {
+        let schema = results_schema();
+
+        for category in CATEGORIES.iter().chain(&["chains", "info"]) {
+            let reference = format!("#/$defs/{}", category);
+
+            assert_eq!(schema["properties"][category]["$ref"], reference);
+            assert!(schema["$defs"][category].is_object());
+        }
+
+        // Every reference has a definition.
+        let defs = schema["$defs"].as_object().unwrap();
+
+        for name in schema
+            .to_string()
+            .split("\"#/$defs/")
+            .skip(1)
+            .map(|rest| rest.split('"').next().unwrap())
+        {
+            assert!(defs.contains_key(name), "missing definition {}", name);
+        }
+    }
+}
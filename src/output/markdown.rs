@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use memflow::prelude::v1::*;
+
+use super::{ButtonMap, SchemaMap};
+
+/// Returns the heading of a Markdown document with the build number and time of the dump.
+pub fn heading(title: &str, build_number: Option<u32>, timestamp: DateTime<Utc>) -> String {
+    match build_number {
+        Some(build_number) => format!("# {} (build {}, {})\n", title, build_number, timestamp),
+        None => format!("# {} ({})\n", title, timestamp),
+    }
+}
+
+/// Returns a table with a row per entry of an offset or interface map, in the order of their
+/// module and name.
+pub fn modules_table(modules: &BTreeMap<String, BTreeMap<String, umem>>) -> String {
+    let mut out = String::from(
+        "\n| Module | Name | Offset (hex) | Offset (dec) |\n| --- | --- | --- | --- |\n",
+    );
+
+    for (module_name, entries) in modules {
+        for (name, value) in entries {
+            out.push_str(&format!(
+                "| {} | `{}` | `{:#X}` | `{}` |\n",
+                module_name, name, value, value
+            ));
+        }
+    }
+
+    out
+}
+
+pub fn buttons_table(buttons: &ButtonMap) -> String {
+    let mut out = String::from("\n| Index | Name | Offset |\n| --- | --- | --- |\n");
+
+    for (index, (name, value)) in buttons.iter().enumerate() {
+        out.push_str(&format!("| {} | `{}` | `{:#X}` |\n", index, name, value));
+    }
+
+    out
+}
+
+/// Returns a section per module with a table per schema class.
+pub fn schema_tables(schemas: &SchemaMap) -> String {
+    let mut out = String::new();
+
+    for (module_name, (classes, _)) in schemas {
+        out.push_str(&format!("\n## {}\n", module_name));
+
+        for class in classes {
+            out.push_str(&format!(
+                "\n### {}\n\n| Field | Offset | Type |\n| --- | --- | --- |\n",
+                class.name
+            ));
+
+            for field in &class.fields {
+                out.push_str(&format!(
+                    "| `{}` | `{:#X}` | `{}` |\n",
+                    field.name, field.offset, field.type_name
+                ));
+            }
+        }
+    }
+
+    out
+}
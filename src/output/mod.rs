@@ -1,213 +1,1998 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::{self, Write};
-use std::fs;
-use std::path::Path;
+use std::fs::{self, File};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-use anyhow::{Result, anyhow};
+use anyhow::{Result, bail};
 
 use chrono::{DateTime, Utc};
 
+use heck::AsTitleCase;
+
+use log::{debug, info, warn};
+
 use memflow::prelude::v1::*;
 
-use serde_json::json;
+use rayon::prelude::*;
+
+use serde_json::{Value, json};
+
+use tera::{Context, Tera};
 
-use formatter::Formatter;
+pub use compression::Compression;
+pub use diff::{read_json_dump, read_json_dumps};
+pub use progress::{ProgressCallback, ProgressEvent};
+pub use registry::{FileWriter, OutputRegistry, WriterFactory};
+pub use util::slugify;
+
+pub use formatter::{CaseConvention, Formatter, IndentKind, IndentStyle, LineEnding, ValueFormat};
+pub use options::CodeOptions;
+
+use archive::Archive;
+use progress::Progress;
+use python_package::PACKAGE_NAME;
+use rust_crate::CrateFile;
+use util::slugify_with_case;
 
 use crate::analysis::*;
+use crate::config::DumpConfig;
 
+mod archive;
 mod buttons;
+mod chains;
+mod cheat_engine;
+mod compression;
+mod csv;
+mod diff;
+mod flatbuffers;
 mod formatter;
+mod ghidra;
+mod go_module;
+mod ida;
 mod interfaces;
+mod json_schema;
+mod markdown;
+mod npm_package;
 mod offsets;
+mod options;
+mod progress;
+mod proto;
+mod python_package;
+mod reclass;
+mod registry;
+mod rust_crate;
 mod schemas;
+mod sqlite;
+pub mod util;
+mod vtables;
+mod x64dbg;
+mod xml;
+
+/// All file types that can be generated.
+pub const FILE_TYPES: &[&str] = &[
+    "cs", "csv", "ct", "fbs", "ghidra", "go", "hpp", "idc", "java", "json", "jsonl", "lua",
+    "markdown", "proto", "py", "rs", "reclass", "sqlite", "toml", "ts", "x64dbg", "xml", "yaml",
+    "zig",
+];
+
+/// The file types that are generated if none are given: the source files of each language and
+/// the data files. The files of other tools and the binary files have to be requested.
+pub const DEFAULT_FILE_TYPES: &[&str] = &[
+    "cs", "go", "hpp", "java", "json", "lua", "py", "rs", "toml", "ts", "yaml", "zig",
+];
+
+/// Alternative names of file types, which are accepted wherever a file type is.
+pub const FILE_TYPE_ALIASES: &[(&str, &str)] = &[("md", "markdown")];
+
+/// The file type that is only used for the Markdown tables of the buttons, interfaces, offsets and
+/// schemas, and the changelog of a diff.
+const MARKDOWN_FILE_TYPE: &str = "markdown";
+
+/// The file type that is only used for the IDA Pro labelling script of the offsets.
+const IDC_FILE_TYPE: &str = "idc";
+
+/// The file type that is only used for the CSV tables of the offsets and interfaces.
+const CSV_FILE_TYPE: &str = "csv";
+
+/// The file type that is only used for the Cheat Engine table of the offsets.
+const CT_FILE_TYPE: &str = "ct";
+
+/// The file type that is only used for the FlatBuffers schema and binary file of a dump.
+const FBS_FILE_TYPE: &str = "fbs";
+
+/// The file type that is only used for the Ghidra scripts of the offsets and schemas.
+const GHIDRA_FILE_TYPE: &str = "ghidra";
+
+/// The file type that is only used for the Protobuf definitions of a dump and the schemas.
+const PROTO_FILE_TYPE: &str = "proto";
 
-enum Item<'a> {
+/// The file type that is only used for the ReClass.NET classes of the schemas.
+const RECLASS_FILE_TYPE: &str = "reclass";
+
+/// The file type that is only used for the SQLite database of the offsets, interfaces and schemas.
+const SQLITE_FILE_TYPE: &str = "sqlite";
+
+/// The file type that is only used for the x64dbg label databases of the offsets.
+const X64DBG_FILE_TYPE: &str = "x64dbg";
+
+/// The file type that is only used for the XML document of the buttons, interfaces, offsets and
+/// schemas.
+const XML_FILE_TYPE: &str = "xml";
+
+/// The file types that aren't generated with a [`CodeWriter`].
+const NON_CODE_FILE_TYPES: &[&str] = &[
+    CSV_FILE_TYPE,
+    CT_FILE_TYPE,
+    FBS_FILE_TYPE,
+    GHIDRA_FILE_TYPE,
+    IDC_FILE_TYPE,
+    MARKDOWN_FILE_TYPE,
+    PROTO_FILE_TYPE,
+    RECLASS_FILE_TYPE,
+    SQLITE_FILE_TYPE,
+    X64DBG_FILE_TYPE,
+    XML_FILE_TYPE,
+];
+
+const JAVA_PACKAGE: &str = "com.cs2dumper";
+
+/// The URL of the generator, which is linked in the banner of each file.
+const GENERATOR_URL: &str = "https://github.com/a2x/cs2-dumper";
+
+/// The number of lines of each file that are printed in a dry run.
+const DRY_RUN_PREVIEW_LINES: usize = 10;
+
+pub(crate) enum Item<'a> {
     Buttons(&'a ButtonMap),
+    Chains(&'a ChainMap),
     Interfaces(&'a InterfaceMap),
     Offsets(&'a OffsetMap),
     Schemas(&'a SchemaMap),
+    Vtables(&'a VtableMap),
+    Diff(&'a DumpDiff),
 }
 
 impl<'a> Item<'a> {
-    fn write(&self, fmt: &mut Formatter<'a>, file_type: &str) -> fmt::Result {
+    fn category(&self) -> &'static str {
+        match self {
+            Item::Buttons(_) => "buttons",
+            Item::Chains(_) => "chains",
+            Item::Interfaces(_) => "interfaces",
+            Item::Offsets(_) => "offsets",
+            Item::Schemas(_) => "schemas",
+            Item::Vtables(_) => "vtables",
+            Item::Diff(_) => "diff",
+        }
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        let mut out = String::new();
+
+        self.write_json(
+            &mut Formatter::new(&mut out, IndentStyle::Spaces(0), ValueFormat::Decimal),
+            &CodeOptions::default(),
+        )?;
+
+        Ok(serde_json::from_str(&out)?)
+    }
+
+    fn write(
+        &self,
+        fmt: &mut Formatter<'a>,
+        opts: &CodeOptions<'_>,
+        file_type: &str,
+    ) -> fmt::Result {
         match file_type {
-            "cs" => self.write_cs(fmt),
-            "hpp" => self.write_hpp(fmt),
-            "json" => self.write_json(fmt),
-            "rs" => self.write_rs(fmt),
-            "zig" => self.write_zig(fmt),
+            "cs" => self.write_cs(fmt, opts),
+            "go" => self.write_go(fmt, opts),
+            "hpp" => self.write_hpp(fmt, opts),
+            "java" => self.write_java(fmt, opts),
+            "json" => self.write_json(fmt, opts),
+            "jsonl" => self.write_jsonl(fmt, opts),
+            "lua" => self.write_lua(fmt, opts),
+            "py" => self.write_py(fmt, opts),
+            "rs" => self.write_rs(fmt, opts),
+            "toml" => self.write_toml(fmt, opts),
+            "ts" => self.write_ts(fmt, opts),
+            "yaml" => self.write_yaml(fmt, opts),
+            "zig" => self.write_zig(fmt, opts),
             _ => unimplemented!(),
         }
     }
 }
 
 trait CodeWriter {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_go(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_json(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_lua(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_py(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_toml(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+
+    // The YAML files mirror the JSON files, where the entries of a module only appear once per
+    // file, so there's no repeated content to share with anchors.
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result;
 }
 
 impl<'a> CodeWriter for Item<'a> {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_cs(fmt, opts),
+            Item::Chains(chains) => chains.write_cs(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_cs(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_cs(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_cs(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_cs(fmt, opts),
+            Item::Diff(diff) => diff.write_cs(fmt, opts),
+        }
+    }
+
+    fn write_go(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_go(fmt, opts),
+            Item::Chains(chains) => chains.write_go(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_go(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_go(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_go(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_go(fmt, opts),
+            Item::Diff(diff) => diff.write_go(fmt, opts),
+        }
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_hpp(fmt, opts),
+            Item::Chains(chains) => chains.write_hpp(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_hpp(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_hpp(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_hpp(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_hpp(fmt, opts),
+            Item::Diff(diff) => diff.write_hpp(fmt, opts),
+        }
+    }
+
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_java(fmt, opts),
+            Item::Chains(chains) => chains.write_java(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_java(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_java(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_java(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_java(fmt, opts),
+            Item::Diff(diff) => diff.write_java(fmt, opts),
+        }
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_json(fmt, opts),
+            Item::Chains(chains) => chains.write_json(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_json(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_json(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_json(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_json(fmt, opts),
+            Item::Diff(diff) => diff.write_json(fmt, opts),
+        }
+    }
+
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_jsonl(fmt, opts),
+            Item::Chains(chains) => chains.write_jsonl(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_jsonl(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_jsonl(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_jsonl(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_jsonl(fmt, opts),
+            Item::Diff(diff) => diff.write_jsonl(fmt, opts),
+        }
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_lua(fmt, opts),
+            Item::Chains(chains) => chains.write_lua(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_lua(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_lua(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_lua(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_lua(fmt, opts),
+            Item::Diff(diff) => diff.write_lua(fmt, opts),
+        }
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        match self {
+            Item::Buttons(buttons) => buttons.write_py(fmt, opts),
+            Item::Chains(chains) => chains.write_py(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_py(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_py(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_py(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_py(fmt, opts),
+            Item::Diff(diff) => diff.write_py(fmt, opts),
+        }
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         match self {
-            Item::Buttons(buttons) => buttons.write_cs(fmt),
-            Item::Interfaces(ifaces) => ifaces.write_cs(fmt),
-            Item::Offsets(offsets) => offsets.write_cs(fmt),
-            Item::Schemas(schemas) => schemas.write_cs(fmt),
+            Item::Buttons(buttons) => buttons.write_rs(fmt, opts),
+            Item::Chains(chains) => chains.write_rs(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_rs(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_rs(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_rs(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_rs(fmt, opts),
+            Item::Diff(diff) => diff.write_rs(fmt, opts),
         }
     }
 
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_toml(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         match self {
-            Item::Buttons(buttons) => buttons.write_hpp(fmt),
-            Item::Interfaces(ifaces) => ifaces.write_hpp(fmt),
-            Item::Offsets(offsets) => offsets.write_hpp(fmt),
-            Item::Schemas(schemas) => schemas.write_hpp(fmt),
+            Item::Buttons(buttons) => buttons.write_toml(fmt, opts),
+            Item::Chains(chains) => chains.write_toml(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_toml(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_toml(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_toml(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_toml(fmt, opts),
+            Item::Diff(diff) => diff.write_toml(fmt, opts),
         }
     }
 
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         match self {
-            Item::Buttons(buttons) => buttons.write_json(fmt),
-            Item::Interfaces(ifaces) => ifaces.write_json(fmt),
-            Item::Offsets(offsets) => offsets.write_json(fmt),
-            Item::Schemas(schemas) => schemas.write_json(fmt),
+            Item::Buttons(buttons) => buttons.write_ts(fmt, opts),
+            Item::Chains(chains) => chains.write_ts(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_ts(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_ts(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_ts(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_ts(fmt, opts),
+            Item::Diff(diff) => diff.write_ts(fmt, opts),
         }
     }
 
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         match self {
-            Item::Buttons(buttons) => buttons.write_rs(fmt),
-            Item::Interfaces(ifaces) => ifaces.write_rs(fmt),
-            Item::Offsets(offsets) => offsets.write_rs(fmt),
-            Item::Schemas(schemas) => schemas.write_rs(fmt),
+            Item::Buttons(buttons) => buttons.write_yaml(fmt, opts),
+            Item::Chains(chains) => chains.write_yaml(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_yaml(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_yaml(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_yaml(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_yaml(fmt, opts),
+            Item::Diff(diff) => diff.write_yaml(fmt, opts),
         }
     }
 
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_zig(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         match self {
-            Item::Buttons(buttons) => buttons.write_zig(fmt),
-            Item::Interfaces(ifaces) => ifaces.write_zig(fmt),
-            Item::Offsets(offsets) => offsets.write_zig(fmt),
-            Item::Schemas(schemas) => schemas.write_zig(fmt),
+            Item::Buttons(buttons) => buttons.write_zig(fmt, opts),
+            Item::Chains(chains) => chains.write_zig(fmt, opts),
+            Item::Interfaces(ifaces) => ifaces.write_zig(fmt, opts),
+            Item::Offsets(offsets) => offsets.write_zig(fmt, opts),
+            Item::Schemas(schemas) => schemas.write_zig(fmt, opts),
+            Item::Vtables(vtables) => vtables.write_zig(fmt, opts),
+            Item::Diff(diff) => diff.write_zig(fmt, opts),
         }
     }
 }
 
 pub struct Output<'a> {
-    file_types: &'a [String],
-    indent_size: usize,
-    out_dir: &'a Path,
-    result: &'a AnalysisResult,
+    config: &'a DumpConfig,
+    result: Cow<'a, AnalysisResult>,
+    unfiltered: &'a AnalysisResult,
+    templates: Option<(Tera, Context)>,
+    annotations: BTreeMap<String, String>,
     timestamp: DateTime<Utc>,
+    out_dir: PathBuf,
+    archive: Mutex<Option<Archive<File>>>,
+    registry: OutputRegistry,
+
+    /// The number of files and bytes that would have been written in a dry run.
+    dry_run_totals: Mutex<(usize, usize)>,
 }
 
 impl<'a> Output<'a> {
-    pub fn new(
-        file_types: &'a [String],
-        indent_size: usize,
-        out_dir: &'a Path,
-        result: &'a AnalysisResult,
-    ) -> Result<Self> {
-        fs::create_dir_all(&out_dir)?;
+    pub fn new(config: &'a DumpConfig, result: &'a AnalysisResult) -> Result<Self> {
+        let timestamp = Utc::now();
+
+        // Colons aren't allowed in file names on Windows.
+        let out_dir = if config.timestamp_dir {
+            config
+                .out_dir
+                .join(timestamp.format("%Y-%m-%dT%H-%M-%SZ").to_string())
+        } else {
+            config.out_dir.clone()
+        };
+
+        if !config.stdout && !config.dry_run {
+            fs::create_dir_all(&out_dir)?;
+
+            if config.python_package && !config.zip {
+                fs::create_dir_all(out_dir.join(PACKAGE_NAME))?;
+            }
+
+            if config.npm_package && !config.zip {
+                fs::create_dir_all(out_dir.join(npm_package::SOURCE_DIR))?;
+            }
+
+            if config.go_module && !config.zip {
+                fs::create_dir_all(out_dir.join(go_module::PACKAGE_DIR))?;
+            }
+        }
+
+        let unfiltered = result;
+
+        let result = match result.filtered(config)? {
+            Some(filtered) => Cow::Owned(filtered),
+            None => Cow::Borrowed(result),
+        };
+
+        let templates = match &config.template_dir {
+            Some(dir) => {
+                let tera = Tera::new(&dir.join("*.tera").to_string_lossy())?;
+
+                let mut context = Context::new();
+
+                for category in CATEGORIES {
+                    context.insert(*category, &category_json(&result, category)?);
+                }
+
+                context.insert("timestamp", &timestamp.to_rfc3339());
+
+                Some((tera, context))
+            }
+            None => None,
+        };
+
+        let annotations = match &config.annotations {
+            Some(path) => read_annotations(path, &unfiltered.offsets)?,
+            None => BTreeMap::new(),
+        };
 
         Ok(Self {
-            file_types,
-            indent_size,
-            out_dir,
+            config,
             result,
-            timestamp: Utc::now(),
+            unfiltered,
+            templates,
+            annotations,
+            timestamp,
+            out_dir,
+            archive: Mutex::new(None),
+            registry: OutputRegistry::default(),
+            dry_run_totals: Mutex::new((0, 0)),
         })
     }
 
-    pub fn dump_all<P: MemoryView + Process>(&self, process: &mut P) -> Result<()> {
-        let items = [
+    /// Sets the registry of custom file types to generate.
+    pub fn with_registry(mut self, registry: OutputRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    pub fn dump_all<P: MemoryView + Process>(
+        &self,
+        process: &mut P,
+        progress: Option<&ProgressCallback>,
+    ) -> Result<()> {
+        // Read the previous dump before any of its files get overwritten.
+        let diff = match &self.config.diff {
+            Some(path) => {
+                let previous = diff::read_json_dump(path, &self.config.categories)?;
+                let previous = previous.filtered(self.config)?.unwrap_or(previous);
+
+                Some(self.result.diff(&previous))
+            }
+            None => None,
+        };
+
+        // Each schema module is written to a separate file.
+        let schemas: Vec<_> = if self.is_enabled("schemas") {
+            self.result
+                .schemas
+                .iter()
+                .map(|(module_name, (classes, enums))| {
+                    let map =
+                        SchemaMap::from([(module_name.clone(), (classes.clone(), enums.clone()))]);
+
+                    (slugify(module_name), map)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut items: Vec<_> = [
             ("buttons", Item::Buttons(&self.result.buttons)),
             ("interfaces", Item::Interfaces(&self.result.interfaces)),
             ("offsets", Item::Offsets(&self.result.offsets)),
-        ];
+            ("vtables", Item::Vtables(&self.result.vtables)),
+        ]
+        .into_iter()
+        .filter(|(file_name, _)| self.is_enabled(file_name))
+        .map(|(file_name, item)| (file_name.to_string(), item))
+        .collect();
 
-        for (file_name, item) in &items {
-            self.dump_item(file_name, item)?;
+        // The pointer chains are user-defined, so they're only written if there are any.
+        if self.is_enabled("offsets") && !self.result.chains.is_empty() {
+            items.push(("chains".to_string(), Item::Chains(&self.result.chains)));
         }
 
-        self.dump_schemas()?;
-        self.dump_info(process)?;
+        items.extend(
+            schemas
+                .iter()
+                .map(|(file_name, map)| (file_name.clone(), Item::Schemas(map))),
+        );
 
-        Ok(())
-    }
+        if let Some(diff) = &diff {
+            info!("found {} changes since the previous dump", diff.len());
 
-    fn dump_info<P: MemoryView + Process>(&self, process: &mut P) -> Result<()> {
-        let file_path = self.out_dir.join("info.json");
+            items.push(("diff".to_string(), Item::Diff(diff)));
+        }
 
-        let build_number = self
-            .result
-            .offsets
-            .iter()
-            .find_map(|(module_name, offsets)| {
-                let module = process.module_by_name(module_name).ok()?;
-                let offset = offsets.iter().find(|(name, _)| *name == "dwBuildNumber")?.1;
+        // The build number offset might be in a module that was filtered out.
+        let build_number = read_build_number_from_process(
+            process,
+            &self.unfiltered.offsets,
+            self.unfiltered.address_mode,
+            Some(BUILD_NUMBER_MODULE),
+        );
 
-                process.read::<u32>(module.base + offset).data_part().ok()
-            })
-            .ok_or(anyhow!("failed to read build number"))?;
+        match build_number {
+            Some(build_number) => info!("read build number {}", build_number),
+            None => warn!("failed to read build number"),
+        }
+
+        let write_changelog = diff.is_some() && self.has_file_type(MARKDOWN_FILE_TYPE);
+        let extra_files = self.extra_file_names();
+        let write_info = !self.config.stdout;
+
+        // The database can't be written to stdout, and its rows are keyed by the build number.
+        let write_sqlite = !self.config.stdout
+            && !self.config.dry_run
+            && self.has_file_type(SQLITE_FILE_TYPE)
+            && build_number.is_some();
+
+        if self.has_file_type(SQLITE_FILE_TYPE) && build_number.is_none() {
+            warn!("skipping dump.db without a build number");
+        }
+
+        // The binary file can't be written to stdout either.
+        let write_fbs_binary = !self.config.stdout && self.has_file_type(FBS_FILE_TYPE);
+
+        // Every file is generated and then written, which are counted as separate steps.
+        let total = items.len() * self.code_file_types().count() * 2
+            + write_changelog as usize
+            + extra_files.len()
+            + self.x64dbg_file_count()
+            + write_sqlite as usize
+            + write_fbs_binary as usize
+            + self.config.rust_crate as usize * 2
+            + self.config.python_package as usize * 2
+            + self.config.npm_package as usize * 3
+            + self.config.go_module as usize
+            + write_info as usize;
+
+        if self.config.versioned_file_names && build_number.is_none() {
+            warn!("writing files without a build number suffix");
+        }
+
+        if self.config.zip {
+            let file_name = self.versioned_file_name("dump.zip", build_number);
+            let file = File::create(self.out_dir.join(&*file_name))?;
+
+            *self.archive.lock().unwrap() = Some(Archive::new(file));
+        }
+
+        let progress = Progress::new(progress, total);
+
+        if !self.config.stdout && !self.config.dry_run {
+            info!("writing files to {}", self.out_dir.display());
+        }
+
+        self.dump_items(&items, build_number, &progress)?;
+
+        if let Some(diff) = &diff
+            && write_changelog
+        {
+            let file_name = self.versioned_file_name("CHANGELOG.md", build_number);
+
+            self.write_file(&file_name, &diff.to_markdown())?;
+
+            progress.step(&format!("wrote {}", file_name));
+        }
+
+        for file_name in extra_files {
+            let content = self.generate_extra_file(file_name, build_number)?;
+            let file_name = self.versioned_file_name(file_name, build_number);
+
+            self.write_file(&file_name, &content)?;
+
+            progress.step(&format!("wrote {}", file_name));
+        }
+
+        if write_fbs_binary {
+            let file_name = self.versioned_file_name("dump.bin", build_number);
+
+            self.write_bytes(
+                &file_name,
+                &flatbuffers::to_fbs_binary(&self.result, &self.config.categories, build_number),
+            )?;
+
+            progress.step(&format!("wrote {}", file_name));
+        }
+
+        if self.config.rust_crate {
+            self.dump_rust_crate(&items, build_number)?;
 
+            progress.step("wrote Cargo.toml");
+            progress.step("wrote lib.rs");
+        }
+
+        if self.config.python_package {
+            self.dump_python_package(&items, build_number)?;
+
+            progress.step("wrote setup.py");
+            progress.step(&format!("wrote {}/__init__.py", PACKAGE_NAME));
+        }
+
+        if self.config.npm_package {
+            self.dump_npm_package(&items, build_number)?;
+
+            progress.step("wrote package.json");
+            progress.step("wrote tsconfig.json");
+            progress.step(&format!("wrote {}/index.ts", npm_package::SOURCE_DIR));
+        }
+
+        if self.config.go_module {
+            let mut go_mod = String::new();
+            let mut fmt = self.formatter(&mut go_mod);
+
+            self.write_banner(&mut fmt, "go", build_number)?;
+
+            go_module::write_go_mod(&mut fmt)?;
+
+            self.write_file("go.mod", &go_mod)?;
+
+            progress.step("wrote go.mod");
+        }
+
+        if self.x64dbg_file_count() > 0 {
+            let databases =
+                x64dbg::databases(process, &self.result.offsets, self.result.address_mode)?;
+
+            for (module_name, database) in databases {
+                let file_name = self.versioned_file_name(
+                    &format!("{}_x64dbg.json", slugify(module_name)),
+                    build_number,
+                );
+
+                self.write_file(&file_name, &serde_json::to_string_pretty(&database)?)?;
+
+                progress.step(&format!("wrote {}", file_name));
+            }
+        }
+
+        // The database accumulates the history of every build, so it's never put in a timestamped
+        // directory.
+        if let Some(build_number) = build_number
+            && write_sqlite
+        {
+            sqlite::to_sqlite(
+                &self.config.out_dir.join("dump.db"),
+                &self.result,
+                &self.config.categories,
+                build_number,
+                self.timestamp,
+            )?;
+
+            progress.step("wrote dump.db");
+        }
+
+        // The info file is only useful alongside the other files in the output directory.
+        if write_info {
+            self.dump_info(build_number)?;
+
+            progress.step("wrote info.json");
+        }
+
+        if let Some(archive) = self.archive.lock().unwrap().take() {
+            archive.finish()?;
+        }
+
+        if self.config.dry_run {
+            let (files, bytes) = *self.dry_run_totals.lock().unwrap();
+
+            writeln!(io::stdout(), "[DRY RUN] {} files, {} bytes", files, bytes)?;
+        }
+
+        Ok(())
+    }
+
+    fn dump_info(&self, build_number: Option<u32>) -> Result<()> {
         let content = serde_json::to_string_pretty(&json!({
             "timestamp": self.timestamp.to_rfc3339(),
             "build_number": build_number,
+            "address_mode": self.result.address_mode,
+            "format": self.config.value_format,
+            "modules": module_stats(&self.result),
         }))?;
 
-        fs::write(&file_path, &content)?;
+        self.write_file("info.json", &content)?;
 
         Ok(())
     }
 
-    fn dump_item(&self, file_name: &str, item: &Item) -> Result<()> {
-        for file_type in self.file_types {
-            let mut out = String::new();
-            let mut fmt = Formatter::new(&mut out, self.indent_size);
+    /// Writes the manifest and root module of a crate that includes the generated Rust files.
+    fn dump_rust_crate(&self, items: &[(String, Item)], build_number: Option<u32>) -> Result<()> {
+        let files: Vec<_> = items
+            .iter()
+            // The pointer chains depend on memflow, and the diff has no code.
+            .filter(|(_, item)| !matches!(item, Item::Chains(_) | Item::Diff(_)))
+            .map(|(stem, item)| CrateFile {
+                category: item.category(),
+                stem: stem.clone(),
+                file_name: self
+                    .versioned_file_name(&format!("{}.rs", stem), build_number)
+                    .into_owned(),
+            })
+            .collect();
+
+        let root_namespace = self.root_namespace("cs2_dumper", "::");
 
-            if file_type != "json" {
-                self.write_banner(&mut fmt)?;
-            }
+        let mut cargo_toml = String::new();
+        let mut fmt = self.formatter(&mut cargo_toml);
 
-            item.write(&mut fmt, file_type)?;
+        self.write_banner(&mut fmt, "toml", build_number)?;
 
-            let file_path = self.out_dir.join(format!("{}.{}", file_name, file_type));
+        rust_crate::write_cargo_toml(build_number, &mut fmt)?;
 
-            fs::write(&file_path, out)?;
-        }
+        self.write_file("Cargo.toml", &cargo_toml)?;
+
+        let mut lib_rs = String::new();
+        let mut fmt = self.formatter(&mut lib_rs);
+
+        self.write_banner(&mut fmt, "rs", build_number)?;
+
+        rust_crate::write_lib_rs(&files, &root_namespace, &mut fmt)?;
+
+        self.write_file("lib.rs", &lib_rs)?;
 
         Ok(())
     }
 
-    fn dump_schemas(&self) -> Result<()> {
-        for (module_name, (classes, enums)) in &self.result.schemas {
-            let map = SchemaMap::from([(module_name.clone(), (classes.clone(), enums.clone()))]);
+    /// Writes the setup script and `__init__.py` of the package that contains the generated Python
+    /// files.
+    fn dump_python_package(
+        &self,
+        items: &[(String, Item)],
+        build_number: Option<u32>,
+    ) -> Result<()> {
+        let module_names: Vec<_> = items
+            .iter()
+            .filter(|(_, item)| is_package_module(item))
+            .map(|(module_name, _)| module_name.as_str())
+            .collect();
+
+        let mut setup_py = String::new();
+        let mut fmt = self.formatter(&mut setup_py);
 
-            self.dump_item(&slugify(&module_name), &Item::Schemas(&map))?;
-        }
+        self.write_banner(&mut fmt, "py", build_number)?;
+
+        python_package::write_setup_py(build_number, &mut fmt)?;
+
+        self.write_file("setup.py", &setup_py)?;
+
+        let mut init_py = String::new();
+        let mut fmt = self.formatter(&mut init_py);
+
+        self.write_banner(&mut fmt, "py", build_number)?;
+
+        python_package::write_init_py(&module_names, &mut fmt)?;
+
+        self.write_file(&format!("{}/__init__.py", PACKAGE_NAME), &init_py)?;
 
         Ok(())
     }
 
-    fn write_banner(&self, fmt: &mut Formatter<'_>) -> Result<()> {
-        writeln!(fmt, "// Generated using https://github.com/a2x/cs2-dumper")?;
-        writeln!(fmt, "// {}\n", self.timestamp)?;
+    /// Writes the `package.json`, `tsconfig.json` and `index.ts` of the npm package that contains
+    /// the generated TypeScript files.
+    fn dump_npm_package(&self, items: &[(String, Item)], build_number: Option<u32>) -> Result<()> {
+        let files: Vec<_> = items
+            .iter()
+            .filter(|(_, item)| is_package_module(item))
+            .map(|(stem, item)| (item.category(), stem.as_str()))
+            .collect();
+
+        let root_namespace = self.root_namespace("cs2_dumper", ".");
+
+        self.write_file(
+            "package.json",
+            &serde_json::to_string_pretty(&npm_package::package_json(build_number))?,
+        )?;
+
+        self.write_file(
+            "tsconfig.json",
+            &serde_json::to_string_pretty(&npm_package::tsconfig_json())?,
+        )?;
+
+        let mut index_ts = String::new();
+        let mut fmt = self.formatter(&mut index_ts);
+
+        self.write_banner(&mut fmt, "ts", build_number)?;
+
+        npm_package::write_index_ts(&files, &root_namespace, &mut fmt)?;
+
+        self.write_file(&format!("{}/index.ts", npm_package::SOURCE_DIR), &index_ts)?;
 
         Ok(())
     }
-}
 
-#[inline]
-fn slugify(input: &str) -> String {
-    input.replace(|c: char| !c.is_alphanumeric(), "_")
+    /// Returns the parts of the configured root namespace joined with `separator`, or `default` if
+    /// none is configured.
+    fn root_namespace(&self, default: &str, separator: &str) -> String {
+        match &self.config.root_namespace {
+            Some(parts) => parts.join(separator),
+            None => default.to_string(),
+        }
+    }
+
+    fn dump_items(
+        &self,
+        items: &[(String, Item)],
+        build_number: Option<u32>,
+        progress: &Progress,
+    ) -> Result<()> {
+        let files: Vec<_> = items
+            .iter()
+            .flat_map(|(file_name, item)| {
+                self.code_file_types()
+                    .map(move |file_type| (file_name, item, file_type))
+            })
+            .collect();
+
+        // Each file is written as soon as it's generated, so only one file per thread is held in
+        // memory at a time.
+        let dump_file = |(file_name, item, file_type): (&String, &Item, &str)| -> Result<()> {
+            let file_name = format!("{}.{}", file_name, file_type);
+
+            progress.start(&format!("generating {}", file_name));
+
+            let content = self.generate(&file_name, item, file_type, build_number)?;
+
+            progress.step(&format!("generated {}", file_name));
+
+            let package_dir = match file_type {
+                "py" if self.config.python_package => Some(PACKAGE_NAME),
+                "ts" if self.config.npm_package => Some(npm_package::SOURCE_DIR),
+                "go" if self.config.go_module => Some(go_module::PACKAGE_DIR),
+                _ => None,
+            };
+
+            // The version of a package is the build number, so its module names stay the same.
+            let file_name = match package_dir.filter(|_| is_package_module(item)) {
+                Some(dir) => format!("{}/{}", dir, file_name),
+                None => self
+                    .versioned_file_name(&file_name, build_number)
+                    .into_owned(),
+            };
+
+            self.write_file(&file_name, &content)?;
+
+            // A custom template can write the offsets in a different structure.
+            if let Item::Offsets(offsets) = item
+                && file_type == "json"
+                && self.config.validate
+                && self.templates.is_none()
+            {
+                validate_offset_count(&file_name, &content, offsets)?;
+            }
+
+            progress.step(&format!("wrote {}", file_name));
+
+            Ok(())
+        };
+
+        // The files would be written to stdout in a random order if they were generated in
+        // parallel.
+        if self.config.stdout {
+            files.into_iter().try_for_each(dump_file)
+        } else {
+            files.into_par_iter().try_for_each(dump_file)
+        }
+    }
+
+    fn generate(
+        &self,
+        file_name: &str,
+        item: &Item,
+        file_type: &str,
+        build_number: Option<u32>,
+    ) -> Result<String> {
+        if let Some(content) = self.render_template(file_name, item, file_type)? {
+            return Ok(content);
+        }
+
+        let mut out = String::new();
+        let mut fmt = self.formatter(&mut out);
+
+        // The comment syntax of a custom file type is unknown, so it has no banner.
+        if let Some(factory) = self.registry.get(file_type) {
+            factory(item).write(&mut fmt)?;
+
+            return Ok(out);
+        }
+
+        // JSON has no comments.
+        if file_type != "json" && file_type != "jsonl" {
+            self.write_banner(&mut fmt, file_type, build_number)?;
+        }
+
+        let case_convention = if self.config.language_case {
+            CaseConvention::of_file_type(file_type)
+        } else {
+            CaseConvention::Preserve
+        };
+
+        let opts = CodeOptions {
+            case_convention,
+            root_namespace: self.config.root_namespace.as_deref(),
+            annotations: Some(&self.annotations),
+            cs_explicit_layout: self.config.cs_explicit_layout,
+            cs_helpers: self.config.cs_helpers,
+        };
+
+        item.write(&mut fmt, &opts, file_type)?;
+
+        Ok(out)
+    }
+
+    /// Generates a file returned by [`extra_file_names`](Self::extra_file_names).
+    fn generate_extra_file(&self, file_name: &str, build_number: Option<u32>) -> Result<String> {
+        let mut out = String::new();
+        let mut fmt = self.formatter(&mut out);
+
+        // The other formats have no line comments, so only the Python scripts, the Protobuf
+        // definitions and the FlatBuffers schema have a banner.
+        if file_name.ends_with(".idc") || file_name.ends_with(".py") {
+            self.write_banner(&mut fmt, "py", build_number)?;
+        } else if file_name.ends_with(".proto") || file_name.ends_with(".fbs") {
+            self.write_banner(&mut fmt, "proto", build_number)?;
+        }
+
+        if let Some(title) = file_name.strip_suffix(".md") {
+            fmt.write_str(&markdown::heading(
+                &AsTitleCase(title).to_string(),
+                build_number,
+                self.timestamp,
+            ))?;
+        }
+
+        let result = &self.result;
+
+        match file_name {
+            "buttons.md" => fmt.write_str(&markdown::buttons_table(&result.buttons))?,
+            "interfaces.md" => fmt.write_str(&markdown::modules_table(&result.interfaces))?,
+            "offsets.md" => fmt.write_str(&markdown::modules_table(&result.offsets))?,
+            "schemas.md" => fmt.write_str(&markdown::schema_tables(&result.schemas))?,
+            "interfaces.csv" => fmt.write_str(&csv::to_csv(&result.interfaces)?)?,
+            "offsets.csv" => fmt.write_str(&csv::to_csv(&result.offsets)?)?,
+            "offsets.ct" => cheat_engine::write_ct(&result.offsets, result.address_mode, &mut fmt)?,
+            "offsets.idc" => ida::write_idc(&result.offsets, result.address_mode, &mut fmt)?,
+            "offsets_ghidra.py" => {
+                ghidra::write_offsets(&result.offsets, result.address_mode, &mut fmt)?
+            }
+            "schemas_ghidra.py" => ghidra::write_schemas(&result.schemas, &mut fmt)?,
+            "schemas.reclass.xml" => reclass::write_reclass(&result.schemas, &mut fmt)?,
+            "dump.proto" => proto::write_proto(
+                &result.schemas,
+                &self.root_namespace("cs2_dumper", "."),
+                &mut fmt,
+            )?,
+            "dump.fbs" => {
+                flatbuffers::write_fbs(&self.root_namespace("cs2_dumper", "."), &mut fmt)?
+            }
+            "results.schema.json" => fmt.write_str(
+                &serde_json::to_string_pretty(&json_schema::results_schema()).unwrap(),
+            )?,
+            "dump.xml" => fmt.write_str(&xml::to_xml(
+                result,
+                &self.config.categories,
+                build_number,
+                self.timestamp,
+                self.indent_style(),
+            )?)?,
+            _ => unreachable!(),
+        }
+
+        Ok(out)
+    }
+
+    /// Renders the `<category>.<file type>.tera` template for `item`, if there is one.
+    fn render_template(
+        &self,
+        file_name: &str,
+        item: &Item,
+        file_type: &str,
+    ) -> Result<Option<String>> {
+        let Some((tera, context)) = &self.templates else {
+            return Ok(None);
+        };
+
+        let template_name = format!("{}.{}.tera", item.category(), file_type);
+
+        if !tera.get_template_names().any(|name| name == template_name) {
+            return Ok(None);
+        }
+
+        let mut context = context.clone();
+
+        context.insert("file_name", file_name);
+        context.insert("item", &item.to_json()?);
+
+        Ok(Some(tera.render(&template_name, &context)?))
+    }
+
+    #[inline]
+    fn is_enabled(&self, category: &str) -> bool {
+        self.config.categories.iter().any(|c| c == category)
+    }
+
+    #[inline]
+    fn indent_style(&self) -> IndentStyle {
+        IndentStyle::new(self.config.indent_style, self.config.indent_size)
+    }
+
+    /// Returns a formatter with the configured indentation, value format and line ending.
+    fn formatter<'b>(&self, out: &'b mut String) -> Formatter<'b> {
+        let mut fmt = Formatter::new(out, self.indent_style(), self.config.value_format);
+
+        fmt.set_line_ending(self.config.line_ending);
+
+        fmt
+    }
+
+    #[inline]
+    fn has_file_type(&self, file_type: &str) -> bool {
+        self.config
+            .file_types
+            .iter()
+            .any(|t| canonical_file_type(t) == file_type)
+    }
+
+    /// Returns the file types that are generated with a [`CodeWriter`] or a registered
+    /// [`FileWriter`].
+    fn code_file_types(&self) -> impl Iterator<Item = &str> {
+        let registered = self
+            .registry
+            .file_types()
+            .filter(|file_type| !self.has_file_type(file_type));
+
+        self.config
+            .file_types
+            .iter()
+            .map(|file_type| canonical_file_type(file_type))
+            .filter(|file_type| !NON_CODE_FILE_TYPES.contains(file_type))
+            .chain(registered)
+    }
+
+    /// Returns the names of the files that will be generated for specific categories rather than
+    /// for every item, e.g. the scripts of reverse engineering tools.
+    fn extra_file_names(&self) -> Vec<&'static str> {
+        let mut file_names = Vec::new();
+
+        if self.has_file_type(MARKDOWN_FILE_TYPE) {
+            for (category, file_name) in [
+                ("buttons", "buttons.md"),
+                ("interfaces", "interfaces.md"),
+                ("offsets", "offsets.md"),
+                ("schemas", "schemas.md"),
+            ] {
+                if self.is_enabled(category) {
+                    file_names.push(file_name);
+                }
+            }
+        }
+
+        if self.has_file_type(CSV_FILE_TYPE) {
+            if self.is_enabled("interfaces") {
+                file_names.push("interfaces.csv");
+            }
+
+            if self.is_enabled("offsets") {
+                file_names.push("offsets.csv");
+            }
+        }
+
+        if self.is_enabled("offsets") && self.has_file_type(CT_FILE_TYPE) {
+            file_names.push("offsets.ct");
+        }
+
+        if self.is_enabled("offsets") && self.has_file_type(IDC_FILE_TYPE) {
+            file_names.push("offsets.idc");
+        }
+
+        if self.has_file_type(GHIDRA_FILE_TYPE) {
+            if self.is_enabled("offsets") {
+                file_names.push("offsets_ghidra.py");
+            }
+
+            if self.is_enabled("schemas") {
+                file_names.push("schemas_ghidra.py");
+            }
+        }
+
+        if self.is_enabled("schemas") && self.has_file_type(RECLASS_FILE_TYPE) {
+            file_names.push("schemas.reclass.xml");
+        }
+
+        if self.has_file_type(PROTO_FILE_TYPE) {
+            file_names.push("dump.proto");
+        }
+
+        if self.has_file_type(FBS_FILE_TYPE) {
+            file_names.push("dump.fbs");
+        }
+
+        if self.has_file_type(XML_FILE_TYPE) {
+            file_names.push("dump.xml");
+        }
+
+        if self.config.json_schema {
+            file_names.push("results.schema.json");
+        }
+
+        file_names
+    }
+
+    /// Returns the total number of files that will be generated.
+    fn file_count(&self) -> usize {
+        let items = ["buttons", "interfaces", "offsets", "vtables"]
+            .iter()
+            .filter(|category| self.is_enabled(category))
+            .count();
+
+        let chains = (self.is_enabled("offsets") && !self.result.chains.is_empty()) as usize;
+
+        let schemas = if self.is_enabled("schemas") {
+            self.result.schemas.len()
+        } else {
+            0
+        };
+
+        let diff = self.config.diff.is_some() as usize;
+
+        let code_file_types = self.code_file_types().count();
+        let changelog = diff * self.has_file_type(MARKDOWN_FILE_TYPE) as usize;
+        let extra_files = self.extra_file_names().len();
+
+        (items + chains + schemas + diff) * code_file_types
+            + changelog
+            + extra_files
+            + self.x64dbg_file_count()
+    }
+
+    /// Returns the number of x64dbg databases that will be generated, one per module.
+    fn x64dbg_file_count(&self) -> usize {
+        if self.is_enabled("offsets") && self.has_file_type(X64DBG_FILE_TYPE) {
+            self.result.offsets.len()
+        } else {
+            0
+        }
+    }
+
+    /// Appends the build number to the stem of `file_name` if versioned file names are enabled
+    /// (e.g. `offsets_14041.rs` or `schemas_14041.reclass.xml`).
+    fn versioned_file_name<'n>(
+        &self,
+        file_name: &'n str,
+        build_number: Option<u32>,
+    ) -> Cow<'n, str> {
+        let Some(build_number) = build_number.filter(|_| self.config.versioned_file_names) else {
+            return Cow::Borrowed(file_name);
+        };
+
+        match file_name.split_once('.') {
+            Some((stem, ext)) => Cow::Owned(format!("{}_{}.{}", stem, build_number, ext)),
+            None => Cow::Owned(format!("{}_{}", file_name, build_number)),
+        }
+    }
+
+    fn write_file(&self, file_name: &str, content: &str) -> Result<()> {
+        // Only the code files are generated with the configured line ending, so the other files
+        // (e.g. JSON, Markdown and templates) are converted here.
+        let content = self.config.line_ending.normalize(content);
+
+        if !self.config.stdout {
+            return self.write_bytes(file_name, content.as_bytes());
+        }
+
+        debug!("writing {} to stdout", file_name);
+
+        let mut stdout = io::stdout().lock();
+
+        // A delimiter is only needed to tell multiple files apart.
+        if self.file_count() > 1 {
+            writeln!(stdout, "==> {} <==", file_name)?;
+        }
+
+        stdout.write_all(content.as_bytes())?;
+
+        if !content.ends_with('\n') {
+            writeln!(stdout)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a file to the archive or the output directory. Unlike [`write_file`](Self::write_file),
+    /// the file is never written to stdout, since it might be binary.
+    fn write_bytes(&self, file_name: &str, content: &[u8]) -> Result<()> {
+        if let Some(archive) = self.archive.lock().unwrap().as_mut() {
+            debug!("adding {} to the archive", file_name);
+
+            return archive.add(file_name, content);
+        }
+
+        let compression = self.config.compress;
+
+        // The content is checked before it's compressed, so this is the name without the
+        // compression extension.
+        let is_json = file_name.ends_with(".json");
+
+        let file_name = match compression.extension() {
+            Some(ext) => format!("{}.{}", file_name, ext),
+            None => file_name.to_string(),
+        };
+
+        let compressed = compression.compress(content)?;
+
+        if self.config.dry_run {
+            return self.print_preview(&file_name, content, compressed.len());
+        }
+
+        let path = self.out_dir.join(&file_name);
+
+        debug!("writing {}", path.display());
+
+        fs::write(&path, &compressed)?;
+
+        if self.config.validate {
+            if fs::read(&path)? != compressed {
+                bail!("{} doesn't match the generated content", path.display());
+            }
+
+            if is_json && let Err(err) = serde_json::from_slice::<Value>(content) {
+                bail!("{} isn't valid JSON: {}", path.display(), err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Prints the first lines of a file that would have been written in a dry run, and adds its
+    /// size to the totals.
+    fn print_preview(&self, file_name: &str, content: &[u8], len: usize) -> Result<()> {
+        {
+            let mut totals = self.dry_run_totals.lock().unwrap();
+
+            totals.0 += 1;
+            totals.1 += len;
+        }
+
+        // The files are written in parallel, so stdout is locked for the whole preview.
+        let mut stdout = io::stdout().lock();
+
+        writeln!(stdout, "[DRY RUN] {}:", file_name)?;
+
+        match std::str::from_utf8(content) {
+            Ok(content) => {
+                for line in content.lines().take(DRY_RUN_PREVIEW_LINES) {
+                    writeln!(stdout, "{}", line)?;
+                }
+            }
+            Err(_) => writeln!(stdout, "({} bytes of binary data)", content.len())?,
+        }
+
+        Ok(())
+    }
+
+    fn write_banner(
+        &self,
+        fmt: &mut Formatter<'_>,
+        file_type: &str,
+        build_number: Option<u32>,
+    ) -> Result<()> {
+        if !self.config.write_banner {
+            return Ok(());
+        }
+
+        let comment = match file_type {
+            "lua" => "--",
+            "py" | "toml" | "yaml" => "#",
+            _ => "//",
+        };
+
+        if let Some(template) = &self.config.banner_template {
+            let build_number = match build_number {
+                Some(build_number) => build_number.to_string(),
+                None => "unknown".to_string(),
+            };
+
+            let banner = template
+                .replace("{timestamp}", &self.timestamp.to_string())
+                .replace("{build_number}", &build_number)
+                .replace("{url}", GENERATOR_URL);
+
+            fmt.write_comment(comment, &banner)?;
+        } else {
+            writeln!(fmt, "{} Generated using {}", comment, GENERATOR_URL)?;
+            writeln!(fmt, "{} {}", comment, self.timestamp)?;
+
+            if let Some(build_number) = build_number {
+                writeln!(fmt, "{} Build: {}", comment, build_number)?;
+            }
+        }
+
+        writeln!(fmt)?;
+
+        Ok(())
+    }
+}
+
+/// Reads the descriptions of offsets from a YAML file, keyed by `<module>::<name>`.
+fn read_annotations(path: &Path, offsets: &OffsetMap) -> Result<BTreeMap<String, String>> {
+    let annotations: BTreeMap<String, String> = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+
+    // A typo in a key would otherwise silently drop the description. The keys are checked against
+    // the unfiltered offsets, since the filters can remove offsets that are annotated.
+    for key in annotations.keys() {
+        let exists = key.split_once("::").is_some_and(|(module_name, name)| {
+            offsets
+                .get(module_name)
+                .is_some_and(|offsets| offsets.contains_key(name))
+        });
+
+        if !exists {
+            warn!("annotation for unknown offset \"{}\"", key);
+        }
+    }
+
+    Ok(annotations)
+}
+
+/// Checks that the JSON file of the offsets has an entry for each offset.
+fn validate_offset_count(file_name: &str, content: &str, offsets: &OffsetMap) -> Result<()> {
+    let expected: usize = offsets.values().map(|offsets| offsets.len()).sum();
+
+    let actual: usize = serde_json::from_str::<Value>(content)?
+        .as_object()
+        .map(|modules| {
+            modules
+                .values()
+                .filter_map(|offsets| offsets.as_object())
+                .map(|offsets| offsets.len())
+                .sum()
+        })
+        .unwrap_or(0);
+
+    if actual != expected {
+        bail!(
+            "{} has {} offsets instead of {}",
+            file_name,
+            actual,
+            expected
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns whether the file of `item` is part of the Python, npm and Go packages, which only leave
+/// out the diff since it has no code.
+#[inline]
+fn is_package_module(item: &Item) -> bool {
+    !matches!(item, Item::Diff(_))
+}
+
+/// Returns the file type that `file_type` is an alias of (e.g. `markdown` for `md`), or
+/// `file_type` itself.
+pub fn canonical_file_type(file_type: &str) -> &str {
+    FILE_TYPE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == file_type)
+        .map_or(file_type, |(_, name)| name)
+}
+
+/// Returns the same JSON content that is written to the JSON file of `category`.
+///
+/// The schemas of all modules are combined into a single object.
+pub fn category_json(result: &AnalysisResult, category: &str) -> Result<Value> {
+    let item = match category {
+        "buttons" => Item::Buttons(&result.buttons),
+        "interfaces" => Item::Interfaces(&result.interfaces),
+        "offsets" => Item::Offsets(&result.offsets),
+        "schemas" => Item::Schemas(&result.schemas),
+        "vtables" => Item::Vtables(&result.vtables),
+        _ => bail!("unsupported category \"{}\"", category),
+    };
+
+    item.to_json()
+}
+
+/// Returns the number of offsets, schema classes and interfaces of each module in `result`, which
+/// is written to `info.json`.
+pub fn module_stats(result: &AnalysisResult) -> Value {
+    let mut modules = BTreeMap::<&str, [usize; 3]>::new();
+
+    for (module_name, offsets) in &result.offsets {
+        modules.entry(module_name.as_str()).or_default()[0] = offsets.len();
+    }
+
+    for (module_name, (classes, _)) in &result.schemas {
+        modules.entry(module_name.as_str()).or_default()[1] = classes.len();
+    }
+
+    for (module_name, ifaces) in &result.interfaces {
+        modules.entry(module_name.as_str()).or_default()[2] = ifaces.len();
+    }
+
+    modules
+        .into_iter()
+        .map(|(module_name, [offsets, classes, ifaces])| {
+            let stats = json!({
+                "offset_count": offsets,
+                "schema_class_count": classes,
+                "interface_count": ifaces,
+            });
+
+            (module_name.to_string(), stats)
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+#[inline]
+fn cpp_ident(input: &str) -> String {
+    if is_cpp_keyword(input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[inline]
+fn is_cpp_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "alignas"
+            | "alignof"
+            | "and"
+            | "and_eq"
+            | "asm"
+            | "auto"
+            | "bitand"
+            | "bitor"
+            | "bool"
+            | "break"
+            | "case"
+            | "catch"
+            | "char"
+            | "char16_t"
+            | "char32_t"
+            | "char8_t"
+            | "class"
+            | "co_await"
+            | "co_return"
+            | "co_yield"
+            | "compl"
+            | "concept"
+            | "const"
+            | "const_cast"
+            | "consteval"
+            | "constexpr"
+            | "constinit"
+            | "continue"
+            | "decltype"
+            | "default"
+            | "delete"
+            | "do"
+            | "double"
+            | "dynamic_cast"
+            | "else"
+            | "enum"
+            | "explicit"
+            | "export"
+            | "extern"
+            | "false"
+            | "float"
+            | "for"
+            | "friend"
+            | "goto"
+            | "if"
+            | "inline"
+            | "int"
+            | "long"
+            | "mutable"
+            | "namespace"
+            | "new"
+            | "noexcept"
+            | "not"
+            | "not_eq"
+            | "nullptr"
+            | "operator"
+            | "or"
+            | "or_eq"
+            | "private"
+            | "protected"
+            | "public"
+            | "register"
+            | "reinterpret_cast"
+            | "requires"
+            | "return"
+            | "short"
+            | "signed"
+            | "sizeof"
+            | "static"
+            | "static_assert"
+            | "static_cast"
+            | "struct"
+            | "switch"
+            | "template"
+            | "this"
+            | "thread_local"
+            | "throw"
+            | "true"
+            | "try"
+            | "typedef"
+            | "typeid"
+            | "typename"
+            | "union"
+            | "unsigned"
+            | "using"
+            | "virtual"
+            | "void"
+            | "volatile"
+            | "wchar_t"
+            | "while"
+            | "xor"
+            | "xor_eq"
+    )
+}
+
+#[inline]
+fn cs_ident(input: &str) -> String {
+    if is_cs_keyword(input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[inline]
+fn is_cs_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "abstract"
+            | "as"
+            | "base"
+            | "bool"
+            | "break"
+            | "byte"
+            | "case"
+            | "catch"
+            | "char"
+            | "checked"
+            | "class"
+            | "const"
+            | "continue"
+            | "decimal"
+            | "default"
+            | "delegate"
+            | "do"
+            | "double"
+            | "else"
+            | "enum"
+            | "event"
+            | "explicit"
+            | "extern"
+            | "false"
+            | "finally"
+            | "fixed"
+            | "float"
+            | "for"
+            | "foreach"
+            | "goto"
+            | "if"
+            | "implicit"
+            | "in"
+            | "int"
+            | "interface"
+            | "internal"
+            | "is"
+            | "lock"
+            | "long"
+            | "namespace"
+            | "new"
+            | "null"
+            | "object"
+            | "operator"
+            | "out"
+            | "override"
+            | "params"
+            | "private"
+            | "protected"
+            | "public"
+            | "readonly"
+            | "ref"
+            | "return"
+            | "sbyte"
+            | "sealed"
+            | "short"
+            | "sizeof"
+            | "stackalloc"
+            | "static"
+            | "string"
+            | "struct"
+            | "switch"
+            | "this"
+            | "throw"
+            | "true"
+            | "try"
+            | "typeof"
+            | "uint"
+            | "ulong"
+            | "unchecked"
+            | "unsafe"
+            | "ushort"
+            | "using"
+            | "virtual"
+            | "void"
+            | "volatile"
+            | "while"
+    )
+}
+
+#[inline]
+fn go_ident(input: &str) -> String {
+    let mut chars = input.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() => {
+            format!("{}{}", c.to_ascii_uppercase(), chars.as_str())
+        }
+        _ => format!("X{}", input),
+    }
+}
+
+#[inline]
+fn java_ident(input: &str) -> String {
+    if is_java_keyword(input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[inline]
+fn is_java_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "abstract"
+            | "assert"
+            | "boolean"
+            | "break"
+            | "byte"
+            | "case"
+            | "catch"
+            | "char"
+            | "class"
+            | "const"
+            | "continue"
+            | "default"
+            | "do"
+            | "double"
+            | "else"
+            | "enum"
+            | "extends"
+            | "false"
+            | "final"
+            | "finally"
+            | "float"
+            | "for"
+            | "goto"
+            | "if"
+            | "implements"
+            | "import"
+            | "instanceof"
+            | "int"
+            | "interface"
+            | "long"
+            | "native"
+            | "new"
+            | "null"
+            | "package"
+            | "private"
+            | "protected"
+            | "public"
+            | "return"
+            | "short"
+            | "static"
+            | "strictfp"
+            | "super"
+            | "switch"
+            | "synchronized"
+            | "this"
+            | "throw"
+            | "throws"
+            | "transient"
+            | "true"
+            | "try"
+            | "void"
+            | "volatile"
+            | "while"
+    )
+}
+
+#[inline]
+fn lua_key(input: &str) -> String {
+    if is_identifier(input) && !is_lua_keyword(input) {
+        input.to_string()
+    } else {
+        let escaped = input.replace('\\', "\\\\").replace('"', "\\\"");
+
+        format!("[\"{}\"]", escaped)
+    }
+}
+
+#[inline]
+fn is_lua_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "and"
+            | "break"
+            | "do"
+            | "else"
+            | "elseif"
+            | "end"
+            | "false"
+            | "for"
+            | "function"
+            | "goto"
+            | "if"
+            | "in"
+            | "local"
+            | "nil"
+            | "not"
+            | "or"
+            | "repeat"
+            | "return"
+            | "then"
+            | "true"
+            | "until"
+            | "while"
+    )
+}
+
+#[inline]
+fn py_ident(input: &str) -> String {
+    if is_py_keyword(input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[inline]
+fn signed_hex(value: i64) -> String {
+    if value < 0 {
+        value.to_string()
+    } else {
+        format!("{:#X}", value)
+    }
+}
+
+#[inline]
+fn is_py_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "False"
+            | "None"
+            | "True"
+            | "and"
+            | "as"
+            | "assert"
+            | "async"
+            | "await"
+            | "break"
+            | "class"
+            | "continue"
+            | "def"
+            | "del"
+            | "elif"
+            | "else"
+            | "except"
+            | "finally"
+            | "for"
+            | "from"
+            | "global"
+            | "if"
+            | "import"
+            | "in"
+            | "is"
+            | "lambda"
+            | "nonlocal"
+            | "not"
+            | "or"
+            | "pass"
+            | "raise"
+            | "return"
+            | "try"
+            | "while"
+            | "with"
+            | "yield"
+    )
+}
+
+/// Returns a raw identifier for a Rust keyword, or appends an underscore to the keywords that can't
+/// be raw identifiers.
+#[inline]
+fn rs_ident(input: &str) -> String {
+    match input {
+        "Self" | "crate" | "self" | "super" => format!("{}_", input),
+        _ if is_rs_keyword(input) => format!("r#{}", input),
+        _ => input.to_string(),
+    }
+}
+
+#[inline]
+fn is_rs_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "Self"
+            | "abstract"
+            | "as"
+            | "async"
+            | "await"
+            | "become"
+            | "box"
+            | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "do"
+            | "dyn"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "final"
+            | "fn"
+            | "for"
+            | "gen"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "macro"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "override"
+            | "priv"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "try"
+            | "type"
+            | "typeof"
+            | "unsafe"
+            | "unsized"
+            | "use"
+            | "virtual"
+            | "where"
+            | "while"
+            | "yield"
+    )
+}
+
+#[inline]
+fn toml_key(input: &str) -> String {
+    let is_bare = !input.is_empty()
+        && input
+            .chars()
+            .all(|c| c == '_' || c == '-' || c.is_ascii_alphanumeric());
+
+    if is_bare {
+        input.to_string()
+    } else {
+        toml_string(input)
+    }
+}
+
+#[inline]
+fn toml_string(input: &str) -> String {
+    toml::Value::from(input).to_string()
+}
+
+#[inline]
+fn ts_ident(input: &str) -> String {
+    if is_ts_keyword(input) {
+        format!("{}_", input)
+    } else {
+        input.to_string()
+    }
+}
+
+#[inline]
+fn is_ts_keyword(input: &str) -> bool {
+    matches!(
+        input,
+        "await"
+            | "break"
+            | "case"
+            | "catch"
+            | "class"
+            | "const"
+            | "continue"
+            | "debugger"
+            | "default"
+            | "delete"
+            | "do"
+            | "else"
+            | "enum"
+            | "export"
+            | "extends"
+            | "false"
+            | "finally"
+            | "for"
+            | "function"
+            | "if"
+            | "implements"
+            | "import"
+            | "in"
+            | "instanceof"
+            | "interface"
+            | "let"
+            | "new"
+            | "null"
+            | "package"
+            | "private"
+            | "protected"
+            | "public"
+            | "return"
+            | "static"
+            | "super"
+            | "switch"
+            | "this"
+            | "throw"
+            | "true"
+            | "try"
+            | "typeof"
+            | "var"
+            | "void"
+            | "while"
+            | "with"
+            | "yield"
+    )
 }
 
 #[inline]
 fn zig_ident(input: &str) -> String {
-    if is_zig_identifier(input) && !is_zig_keyword(input) {
+    if is_identifier(input) && !is_zig_keyword(input) {
         input.to_string()
     } else {
         let escaped = input.replace('\\', "\\\\").replace('"', "\\\"");
@@ -217,7 +2002,7 @@ fn zig_ident(input: &str) -> String {
 }
 
 #[inline]
-fn is_zig_identifier(input: &str) -> bool {
+fn is_identifier(input: &str) -> bool {
     let mut chars = input.chars();
 
     match chars.next() {
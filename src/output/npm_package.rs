@@ -0,0 +1,123 @@
+use std::fmt::{self, Write};
+
+use heck::AsSnakeCase;
+
+use serde_json::{Value, json};
+
+use super::Formatter;
+
+/// The name of the generated package.
+const PACKAGE_NAME: &str = "cs2-offsets";
+
+/// The directory of the TypeScript files within the package.
+pub const SOURCE_DIR: &str = "src";
+
+/// Returns the `package.json` of the package, whose major version is the build number of the game.
+///
+/// The package ships the TypeScript sources, which bundlers compile along with the project that
+/// depends on it.
+pub fn package_json(build_number: Option<u32>) -> Value {
+    json!({
+        "name": PACKAGE_NAME,
+        "version": format!("{}.0.0", build_number.unwrap_or(0)),
+        "description": "Counter-Strike 2 offsets and schemas generated by cs2-dumper",
+        "main": format!("{}/index.ts", SOURCE_DIR),
+        "types": format!("{}/index.ts", SOURCE_DIR),
+        "files": [SOURCE_DIR],
+        "scripts": {
+            "check": "tsc --noEmit"
+        },
+        "devDependencies": {
+            "typescript": "^5.0.0"
+        }
+    })
+}
+
+/// Returns the `tsconfig.json` of the package.
+pub fn tsconfig_json() -> Value {
+    json!({
+        "compilerOptions": {
+            "target": "ES2020",
+            "module": "ESNext",
+            "moduleResolution": "Bundler",
+            "strict": true,
+            "declaration": true,
+            "outDir": "dist"
+        },
+        "include": [SOURCE_DIR]
+    })
+}
+
+/// Writes the `index.ts` of the package, which re-exports the category of each file below its
+/// root namespace (e.g. `offsets.client_dll.dwEntityList`).
+///
+/// `files` contains the category and name without extension of each file.
+pub fn write_index_ts(
+    files: &[(&str, &str)],
+    root_namespace: &str,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    for (_, stem) in files {
+        writeln!(fmt, "import * as {}_file from \"./{}\";", stem, stem)?;
+    }
+
+    writeln!(fmt)?;
+
+    // The schema files all have the same category, so their namespaces are merged.
+    let (schemas, others): (Vec<_>, Vec<_>) = files
+        .iter()
+        .partition(|(category, _)| *category == "schemas");
+
+    for (category, stem) in others {
+        writeln!(
+            fmt,
+            "export import {} = {}_file.{}.{};",
+            category, stem, root_namespace, category
+        )?;
+    }
+
+    if !schemas.is_empty() {
+        writeln!(fmt)?;
+
+        fmt.block("export namespace schemas", false, |fmt| {
+            for (_, stem) in schemas {
+                writeln!(
+                    fmt,
+                    "export import {} = {}_file.{}.schemas.{};",
+                    AsSnakeCase(stem),
+                    stem,
+                    root_namespace,
+                    AsSnakeCase(stem)
+                )?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn index_ts_exports() {
+        let files = [("offsets", "offsets"), ("schemas", "client_dll")];
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_index_ts(&files, "cs2_dumper", &mut fmt).unwrap();
+
+        assert!(out.contains("import * as offsets_file from \"./offsets\";\n"));
+        assert!(out.contains("export import offsets = offsets_file.cs2_dumper.offsets;\n"));
+        assert!(out.contains(
+            "export namespace schemas {\n    export import client_dll = client_dll_file.cs2_dumper.schemas.client_dll;\n}"
+        ));
+
+        assert_eq!(package_json(Some(14041))["version"], "14041.0.0");
+    }
+}
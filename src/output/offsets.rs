@@ -2,11 +2,21 @@ use std::fmt::{self, Write};
 
 use heck::{AsPascalCase, AsSnakeCase};
 
-use super::{CodeWriter, Formatter, OffsetMap, slugify, zig_ident};
+use serde_json::json;
+
+use super::{
+    CodeOptions, CodeWriter, Formatter, JAVA_PACKAGE, OffsetMap, OffsetType, cpp_ident, cs_ident,
+    java_ident, lua_key, offset_type, py_ident, rs_ident, slugify, toml_key, ts_ident, zig_ident,
+};
 
 impl CodeWriter for OffsetMap {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        fmt.block("namespace CS2Dumper.Offsets", false, |fmt| {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "namespace {}.Offsets",
+            opts.root_namespace("CS2Dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
             for (module_name, offsets) in self {
                 writeln!(fmt, "// Module: {}", module_name)?;
 
@@ -15,7 +25,21 @@ impl CodeWriter for OffsetMap {
                     false,
                     |fmt| {
                         for (name, value) in offsets {
-                            writeln!(fmt, "public const nint {} = {:#X};", name, value)?;
+                            if let Some(annotation) = opts.annotation(module_name, name) {
+                                fmt.write_comment("///", annotation)?;
+                            }
+
+                            writeln!(
+                                fmt,
+                                "public const nint {} = {};{}",
+                                cs_ident(name),
+                                fmt.value(value),
+                                type_comment(name, cs_type)
+                            )?;
+                        }
+
+                        if opts.cs_helpers {
+                            write_cs_helpers(fmt, offsets.keys())?;
                         }
 
                         Ok(())
@@ -27,12 +51,44 @@ impl CodeWriter for OffsetMap {
         })
     }
 
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_go(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+
+        for (module_name, offsets) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+
+            let prefix = AsPascalCase(slugify(module_name)).to_string();
+
+            writeln!(fmt, "const (")?;
+
+            fmt.indent(|fmt| {
+                for (name, value) in offsets {
+                    writeln!(
+                        fmt,
+                        "{}_{} = {}",
+                        prefix,
+                        opts.const_name(name),
+                        fmt.value(value)
+                    )?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, ")")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#pragma once\n")?;
         writeln!(fmt, "#include <cstddef>")?;
         writeln!(fmt, "#include <cstdint>\n")?;
 
-        fmt.block("namespace cs2_dumper", false, |fmt| {
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
             fmt.block("namespace offsets", false, |fmt| {
                 for (module_name, offsets) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -42,7 +98,17 @@ impl CodeWriter for OffsetMap {
                         false,
                         |fmt| {
                             for (name, value) in offsets {
-                                writeln!(fmt, "constexpr std::ptrdiff_t {} = {:#X};", name, value)?;
+                                if let Some(annotation) = opts.annotation(module_name, name) {
+                                    fmt.write_comment("///", annotation)?;
+                                }
+
+                                writeln!(
+                                    fmt,
+                                    "constexpr std::ptrdiff_t {} = {};{}",
+                                    cpp_ident(name),
+                                    fmt.value(value),
+                                    type_comment(name, hpp_type)
+                                )?;
                             }
 
                             Ok(())
@@ -55,14 +121,118 @@ impl CodeWriter for OffsetMap {
         })
     }
 
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+
+        fmt.block("public final class offsets", false, |fmt| {
+            for (module_name, offsets) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static final class {}", slugify(module_name)),
+                    false,
+                    |fmt| {
+                        for (name, value) in offsets {
+                            writeln!(
+                                fmt,
+                                "public static final long {} = {}L;",
+                                java_ident(name),
+                                fmt.value(value)
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         fmt.write_str(&serde_json::to_string_pretty(self).unwrap())
     }
 
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, offsets) in self {
+            for (name, value) in offsets {
+                fmt.write_jsonl_record(&json!({
+                    "module": module_name,
+                    "name": name,
+                    "value": value,
+                }))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "offsets = {{")?;
+
+            fmt.indent(|fmt| {
+                for (module_name, offsets) in self {
+                    writeln!(fmt, "-- Module: {}", module_name)?;
+
+                    let module_name = lua_key(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    writeln!(fmt, "{} = {{", module_name)?;
+
+                    fmt.indent(|fmt| {
+                        for (name, value) in offsets {
+                            writeln!(fmt, "{} = {},", lua_key(name), fmt.value(value))?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "}},")?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, offsets) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "class {}:", AsSnakeCase(slugify(module_name)))?;
+
+            fmt.indent(|fmt| {
+                if offsets.is_empty() {
+                    return writeln!(fmt, "pass");
+                }
+
+                for (name, value) in offsets {
+                    writeln!(
+                        fmt,
+                        "{} = {}",
+                        py_ident(&opts.const_name(name)),
+                        fmt.value(value)
+                    )?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#![allow(non_upper_case_globals, unused)]\n")?;
 
-        fmt.block("pub mod cs2_dumper", false, |fmt| {
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
             fmt.block("pub mod offsets", false, |fmt| {
                 for (module_name, offsets) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -72,7 +242,17 @@ impl CodeWriter for OffsetMap {
                         false,
                         |fmt| {
                             for (name, value) in offsets {
-                                writeln!(fmt, "pub const {}: usize = {:#X};", name, value)?;
+                                if let Some(annotation) = opts.annotation(module_name, name) {
+                                    fmt.write_comment("///", annotation)?;
+                                }
+
+                                writeln!(
+                                    fmt,
+                                    "pub const {}: usize = {};{}",
+                                    rs_ident(&opts.const_name(name)),
+                                    fmt.value(value),
+                                    type_comment(name, rs_type)
+                                )?;
                             }
 
                             Ok(())
@@ -85,7 +265,58 @@ impl CodeWriter for OffsetMap {
         })
     }
 
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, offsets) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "[{}]", toml_key(module_name))?;
+
+            for (name, value) in offsets {
+                writeln!(fmt, "{} = {}", toml_key(name), fmt.value(value))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("export namespace offsets", false, |fmt| {
+                for (module_name, offsets) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("export namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, value) in offsets {
+                                writeln!(
+                                    fmt,
+                                    "export const {} = {};",
+                                    ts_ident(name),
+                                    fmt.value(value)
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_yaml::to_string(self).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         fmt.block("pub const cs2_dumper = struct", true, |fmt| {
             fmt.block("pub const offsets = struct", true, |fmt| {
                 for (module_name, offsets) in self {
@@ -100,9 +331,9 @@ impl CodeWriter for OffsetMap {
                             for (name, value) in offsets {
                                 writeln!(
                                     fmt,
-                                    "pub const {}: usize = {:#X};",
+                                    "pub const {}: usize = {};",
                                     zig_ident(name),
-                                    value
+                                    fmt.value(value)
                                 )?;
                             }
 
@@ -116,3 +347,237 @@ impl CodeWriter for OffsetMap {
         })
     }
 }
+
+/// Writes a method that reads the value of each offset with a known type from the base address of
+/// its module.
+fn write_cs_helpers<'a>(
+    fmt: &mut Formatter<'_>,
+    names: impl IntoIterator<Item = &'a String>,
+) -> fmt::Result {
+    for (name, offset_type) in names
+        .into_iter()
+        .filter_map(|name| Some((name, offset_type(name)?)))
+    {
+        let type_name = cs_type(offset_type);
+
+        writeln!(fmt)?;
+        writeln!(
+            fmt,
+            "public static unsafe {} Read{}(nint baseAddr) => *({}*)(baseAddr + {});",
+            type_name,
+            AsPascalCase(name),
+            type_name,
+            cs_ident(name)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns a trailing comment with the value type of the offset, if known.
+fn type_comment(name: &str, f: fn(OffsetType) -> &'static str) -> String {
+    offset_type(name)
+        .map(|offset_type| format!(" // {}", f(offset_type)))
+        .unwrap_or_default()
+}
+
+fn cs_type(offset_type: OffsetType) -> &'static str {
+    match offset_type {
+        OffsetType::U8 => "byte",
+        OffsetType::U16 => "ushort",
+        OffsetType::U32 => "uint",
+        OffsetType::U64 => "ulong",
+        OffsetType::Ptr => "nint",
+        OffsetType::Bool => "bool",
+        OffsetType::Float => "float",
+        OffsetType::Double => "double",
+    }
+}
+
+fn hpp_type(offset_type: OffsetType) -> &'static str {
+    match offset_type {
+        OffsetType::U8 => "std::uint8_t",
+        OffsetType::U16 => "std::uint16_t",
+        OffsetType::U32 => "std::uint32_t",
+        OffsetType::U64 => "std::uint64_t",
+        OffsetType::Ptr => "std::uintptr_t",
+        OffsetType::Bool => "bool",
+        OffsetType::Float => "float",
+        OffsetType::Double => "double",
+    }
+}
+
+fn rs_type(offset_type: OffsetType) -> &'static str {
+    match offset_type {
+        OffsetType::U8 => "u8",
+        OffsetType::U16 => "u16",
+        OffsetType::U32 => "u32",
+        OffsetType::U64 => "u64",
+        OffsetType::Ptr => "usize",
+        OffsetType::Bool => "bool",
+        OffsetType::Float => "f32",
+        OffsetType::Double => "f64",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::output::{CaseConvention, IndentStyle, Item, ValueFormat};
+
+    #[test]
+    fn toml_round_trip() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([
+                ("dwEntityList".to_string(), 0x24CED50),
+                ("dwViewMatrix".to_string(), 0x232EAC0),
+            ]),
+        )]);
+
+        for value_format in [ValueFormat::Hex, ValueFormat::Decimal] {
+            let mut out = String::new();
+            let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), value_format);
+
+            offsets
+                .write_toml(&mut fmt, &CodeOptions::default())
+                .unwrap();
+
+            let parsed: OffsetMap = toml::from_str(&out).unwrap();
+
+            assert_eq!(parsed, offsets);
+        }
+    }
+
+    #[test]
+    fn language_case() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+        )]);
+
+        for (file_type, line) in [
+            ("go", "ClientDll_DwEntityList = 0x24CED50"),
+            ("py", "DW_ENTITY_LIST = 0x24CED50"),
+            ("rs", "pub const DW_ENTITY_LIST: usize = 0x24CED50;"),
+        ] {
+            let mut out = String::new();
+            let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+            let opts = CodeOptions {
+                case_convention: CaseConvention::of_file_type(file_type),
+                ..Default::default()
+            };
+
+            Item::Offsets(&offsets)
+                .write(&mut fmt, &opts, file_type)
+                .unwrap();
+
+            assert!(out.contains(line), "{}", out);
+        }
+    }
+
+    #[test]
+    fn jsonl_records() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([
+                ("dwEntityList".to_string(), 0x24CED50),
+                ("dwViewMatrix".to_string(), 0x232EAC0),
+            ]),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        offsets
+            .write_jsonl(&mut fmt, &CodeOptions::default())
+            .unwrap();
+
+        let records: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            records,
+            [
+                json!({ "module": "client.dll", "name": "dwEntityList", "value": 0x24CED50 }),
+                json!({ "module": "client.dll", "name": "dwViewMatrix", "value": 0x232EAC0 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn annotations() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([
+                ("dwEntityList".to_string(), 0x24CED50),
+                ("dwViewMatrix".to_string(), 0x232EAC0),
+            ]),
+        )]);
+
+        // A block comment would be closed early by the description.
+        let annotations = BTreeMap::from([(
+            "client.dll::dwEntityList".to_string(),
+            "Pointer to the entity list.\nIndexed by entity handle, e.g. */ 0x10.".to_string(),
+        )]);
+
+        for (file_type, comment) in [
+            ("cs", "/// Pointer to the entity list.\n"),
+            ("hpp", "/// Pointer to the entity list.\n"),
+            ("rs", "/// Pointer to the entity list.\n"),
+        ] {
+            let mut out = String::new();
+            let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+            let opts = CodeOptions {
+                annotations: Some(&annotations),
+                ..Default::default()
+            };
+
+            Item::Offsets(&offsets)
+                .write(&mut fmt, &opts, file_type)
+                .unwrap();
+
+            assert!(out.contains(comment), "{}", out);
+            assert!(
+                out.contains("/// Indexed by entity handle, e.g. */ 0x10.\n"),
+                "{}",
+                out
+            );
+            assert_eq!(out.matches(comment.trim()).count(), 1, "{}", out);
+        }
+    }
+
+    #[test]
+    fn cs_helpers() {
+        let offsets = OffsetMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([
+                ("dwEntityList".to_string(), 0x24CED50),
+                ("dwUnknown".to_string(), 0x1000),
+            ]),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        let opts = CodeOptions {
+            cs_helpers: true,
+            ..Default::default()
+        };
+
+        offsets.write_cs(&mut fmt, &opts).unwrap();
+
+        assert!(out.contains(
+            "public static unsafe nint ReadDwEntityList(nint baseAddr) => *(nint*)(baseAddr + dwEntityList);"
+        ));
+
+        // Offsets without a known type have no method.
+        assert!(!out.contains("ReadDwUnknown"), "{}", out);
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::{CaseConvention, Formatter, slugify_with_case};
+
+/// The options that decide what the generated code contains, as opposed to the [`Formatter`],
+/// which only decides how it's laid out.
+#[derive(Clone, Debug, Default)]
+pub struct CodeOptions<'a> {
+    /// The case convention of the names of constants.
+    pub case_convention: CaseConvention,
+
+    /// The parts of the namespace of the generated code, instead of the default one of each
+    /// language.
+    pub root_namespace: Option<&'a [String]>,
+
+    /// The descriptions of offsets, keyed by `<module>::<name>`.
+    pub annotations: Option<&'a BTreeMap<String, String>>,
+
+    /// Whether schema classes are written as C# structs with an explicit layout.
+    pub cs_explicit_layout: bool,
+
+    /// Whether methods that read the values of offsets are written in C#.
+    pub cs_helpers: bool,
+}
+
+impl<'a> CodeOptions<'a> {
+    /// Returns the name of a constant in the configured case convention.
+    #[inline]
+    pub fn const_name(&self, name: &str) -> String {
+        slugify_with_case(name, self.case_convention)
+    }
+
+    /// Returns the description of an offset in the configured annotations, if it has one.
+    pub fn annotation(&self, module_name: &str, name: &str) -> Option<&'a str> {
+        self.annotations?
+            .get(&format!("{}::{}", module_name, name))
+            .map(String::as_str)
+    }
+
+    /// Returns the parts of the configured root namespace joined with `separator`, or `default` if
+    /// none is configured.
+    pub fn root_namespace(&self, default: &str, separator: &str) -> String {
+        match self.root_namespace {
+            Some(parts) => parts.join(separator),
+            None => default.to_string(),
+        }
+    }
+
+    /// Writes a nested `pub mod` block for each part of the configured root namespace, or a single
+    /// block for `default` if none is configured.
+    pub fn root_modules<'f, F>(&self, fmt: &mut Formatter<'f>, default: &str, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Formatter<'f>) -> fmt::Result,
+    {
+        let default = [default.to_string()];
+
+        nested_modules(fmt, self.root_namespace.unwrap_or(&default), f)
+    }
+}
+
+fn nested_modules<'f, F>(fmt: &mut Formatter<'f>, parts: &[String], f: F) -> fmt::Result
+where
+    F: FnOnce(&mut Formatter<'f>) -> fmt::Result,
+{
+    match parts.split_first() {
+        Some((part, rest)) => fmt.block(&format!("pub mod {}", part), false, |fmt| {
+            nested_modules(fmt, rest, f)
+        }),
+        None => f(fmt),
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A stage of [`Output::dump_all`](super::Output::dump_all) and how much of the dump is done.
+#[derive(Clone, Debug)]
+pub struct ProgressEvent {
+    pub stage: String,
+
+    /// The completed fraction of the dump, from `0.0` to `1.0`.
+    pub fraction: f32,
+}
+
+/// Receives the progress of a dump. It's called from multiple threads while files are generated.
+pub type ProgressCallback = Box<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// Tracks the completed steps of a dump and reports them to the callback, if there is one.
+pub(super) struct Progress<'a> {
+    callback: Option<&'a ProgressCallback>,
+    done: AtomicUsize,
+    total: usize,
+}
+
+impl<'a> Progress<'a> {
+    pub fn new(callback: Option<&'a ProgressCallback>, total: usize) -> Self {
+        Self {
+            callback,
+            done: AtomicUsize::new(0),
+            total,
+        }
+    }
+
+    /// Reports the start of a stage without completing a step.
+    pub fn start(&self, stage: &str) {
+        self.report(stage, self.done.load(Ordering::Relaxed));
+    }
+
+    /// Completes a step and reports the stage it belongs to.
+    pub fn step(&self, stage: &str) {
+        self.report(stage, self.done.fetch_add(1, Ordering::Relaxed) + 1);
+    }
+
+    fn report(&self, stage: &str, done: usize) {
+        let Some(callback) = self.callback else {
+            return;
+        };
+
+        let fraction = if self.total == 0 {
+            1.0
+        } else {
+            (done as f32 / self.total as f32).min(1.0)
+        };
+
+        callback(ProgressEvent {
+            stage: stage.to_string(),
+            fraction,
+        });
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+use std::fmt::{self, Write};
+
+use super::{Enum, Formatter, SchemaMap, slugify};
+
+/// Writes a `proto3` file with the messages of a dump and a message per schema class.
+///
+/// The `DumpResult` message holds the entries of a dump. The classes and enums of each module are
+/// nested in a message of the module, since their names are only unique within a module. Each
+/// field of a class is a `uint64` that holds its offset, numbered in the order of the fields.
+pub fn write_proto(schemas: &SchemaMap, package: &str, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "syntax = \"proto3\";\n")?;
+    writeln!(fmt, "package {};\n", package)?;
+
+    write_message(fmt, "Button", &[("string", "name"), ("uint64", "value")])?;
+
+    for message_name in ["Interface", "Offset"] {
+        write_message(
+            fmt,
+            message_name,
+            &[
+                ("string", "module"),
+                ("string", "name"),
+                ("uint64", "value"),
+            ],
+        )?;
+    }
+
+    write_message(
+        fmt,
+        "SchemaField",
+        &[("string", "name"), ("string", "type"), ("uint64", "offset")],
+    )?;
+
+    write_message(
+        fmt,
+        "SchemaClass",
+        &[
+            ("string", "module"),
+            ("string", "name"),
+            ("optional string", "parent"),
+            ("uint64", "size"),
+            ("repeated SchemaField", "fields"),
+        ],
+    )?;
+
+    write_message(
+        fmt,
+        "DumpResult",
+        &[
+            ("optional uint32", "build_number"),
+            ("repeated Button", "buttons"),
+            ("repeated Interface", "interfaces"),
+            ("repeated Offset", "offsets"),
+            ("repeated SchemaClass", "schemas"),
+        ],
+    )?;
+
+    for (module_name, (classes, enums)) in schemas {
+        writeln!(fmt, "// Module: {}", module_name)?;
+
+        fmt.block(&format!("message {}", slugify(module_name)), false, |fmt| {
+            for enum_ in enums {
+                write_enum(fmt, enum_)?;
+            }
+
+            for class in classes {
+                writeln!(fmt, "// Size: {:#X}", class.size)?;
+
+                if let Some(parent_name) = &class.parent_name {
+                    writeln!(fmt, "// Parent: {}", parent_name)?;
+                }
+
+                fmt.block(&format!("message {}", slugify(&class.name)), false, |fmt| {
+                    for (i, field) in class.fields.iter().enumerate() {
+                        writeln!(
+                            fmt,
+                            "uint64 {} = {}; // {:#X} ({})",
+                            slugify(&field.name),
+                            i + 1,
+                            field.offset,
+                            field.type_name
+                        )?;
+                    }
+
+                    Ok(())
+                })?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn write_message(fmt: &mut Formatter<'_>, name: &str, fields: &[(&str, &str)]) -> fmt::Result {
+    fmt.block(&format!("message {}", name), false, |fmt| {
+        for (i, (type_name, field_name)) in fields.iter().enumerate() {
+            writeln!(fmt, "{} {} = {};", type_name, field_name, i + 1)?;
+        }
+
+        Ok(())
+    })?;
+
+    writeln!(fmt)
+}
+
+/// Writes an enum whose members are prefixed with its name, since the values of all enums in a
+/// message share a scope.
+///
+/// Proto3 enums must start with a zero value and can only hold 32-bit signed values, so a zero
+/// value is added if there is none, and members outside of that range are commented out.
+fn write_enum(fmt: &mut Formatter<'_>, enum_: &Enum) -> fmt::Result {
+    let enum_name = slugify(&enum_.name);
+
+    writeln!(fmt, "// Alignment: {}", enum_.alignment)?;
+
+    fmt.block(&format!("enum {}", enum_name), false, |fmt| {
+        let mut values = HashSet::new();
+
+        let has_aliases = enum_
+            .members
+            .iter()
+            .filter_map(|member| i32::try_from(member.value).ok())
+            .any(|value| !values.insert(value));
+
+        if has_aliases {
+            writeln!(fmt, "option allow_alias = true;")?;
+        }
+
+        if !values.contains(&0) {
+            writeln!(fmt, "{}_UNSPECIFIED = 0;", enum_name)?;
+        }
+
+        // The zero value has to come first.
+        let mut members: Vec<_> = enum_.members.iter().collect();
+
+        members.sort_by_key(|member| member.value != 0);
+
+        for member in members {
+            let name = format!("{}_{}", enum_name, slugify(&member.name));
+
+            match i32::try_from(member.value) {
+                Ok(value) => writeln!(fmt, "{} = {};", name, value)?,
+                Err(_) => writeln!(fmt, "// {} = {}; (out of range)", name, member.value)?,
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{Class, ClassField, EnumMember};
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn proto_schemas() {
+        let schemas = SchemaMap::from([(
+            "client.dll".to_string(),
+            (
+                vec![Class {
+                    name: "C_BaseEntity".to_string(),
+                    module_name: "client.dll".to_string(),
+                    parent_name: Some("CEntityInstance".to_string()),
+                    size: 0x10,
+                    alignment: 8,
+                    metadata: Vec::new(),
+                    fields: vec![ClassField {
+                        name: "m_iHealth".to_string(),
+                        type_name: "int32".to_string(),
+                        offset: 0x8,
+                        alignment: Some(4),
+                    }],
+                }],
+                vec![Enum {
+                    name: "ObserverMode_t".to_string(),
+                    alignment: 4,
+                    size: 2,
+                    members: vec![
+                        EnumMember {
+                            name: "OBS_MODE_FIXED".to_string(),
+                            value: 1,
+                        },
+                        EnumMember {
+                            name: "OBS_MODE_FLAGS".to_string(),
+                            value: 0x1_0000_0000,
+                        },
+                    ],
+                }],
+            ),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_proto(&schemas, "cs2_dumper", &mut fmt).unwrap();
+
+        assert!(out.starts_with("syntax = \"proto3\";\n\npackage cs2_dumper;\n"));
+        assert!(out.contains(
+            "    message C_BaseEntity {\n        uint64 m_iHealth = 1; // 0x8 (int32)\n    }\n"
+        ));
+        assert!(out.contains(
+            "    enum ObserverMode_t {\n        ObserverMode_t_UNSPECIFIED = 0;\n        ObserverMode_t_OBS_MODE_FIXED = 1;\n        // ObserverMode_t_OBS_MODE_FLAGS = 4294967296; (out of range)\n    }\n"
+        ));
+    }
+}
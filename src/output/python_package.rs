@@ -0,0 +1,71 @@
+use std::fmt::{self, Write};
+
+use super::Formatter;
+
+/// The name of the generated package, which is also its directory in the output directory.
+pub const PACKAGE_NAME: &str = "cs2_offsets";
+
+/// Writes the setup script of the package, whose version is the build number of the game.
+pub fn write_setup_py(build_number: Option<u32>, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "from setuptools import setup\n")?;
+
+    writeln!(fmt, "setup(")?;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "name=\"{}\",", PACKAGE_NAME.replace('_', "-"))?;
+        writeln!(fmt, "version=\"{}\",", build_number.unwrap_or(0))?;
+        writeln!(
+            fmt,
+            "description=\"Counter-Strike 2 offsets and schemas generated by cs2-dumper\","
+        )?;
+        writeln!(fmt, "packages=[\"{}\"],", PACKAGE_NAME)
+    })?;
+
+    writeln!(fmt, ")")
+}
+
+/// Writes the `__init__.py` of the package, which imports each of its modules (e.g. `offsets` or
+/// `client_dll`).
+///
+/// The modules aren't star-imported, since most of them have a class for the same game modules.
+pub fn write_init_py(module_names: &[&str], fmt: &mut Formatter<'_>) -> fmt::Result {
+    for module_name in module_names {
+        writeln!(fmt, "from . import {}", module_name)?;
+    }
+
+    writeln!(fmt)?;
+
+    let names: Vec<_> = module_names
+        .iter()
+        .map(|module_name| format!("\"{}\"", module_name))
+        .collect();
+
+    writeln!(fmt, "__all__ = [{}]", names.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn package_files() {
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_init_py(&["offsets", "client_dll"], &mut fmt).unwrap();
+
+        assert_eq!(
+            out,
+            "from . import offsets\nfrom . import client_dll\n\n__all__ = [\"offsets\", \"client_dll\"]\n"
+        );
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_setup_py(Some(14041), &mut fmt).unwrap();
+
+        assert!(out.contains("    name=\"cs2-offsets\",\n    version=\"14041\",\n"));
+        assert!(out.contains("    packages=[\"cs2_offsets\"],\n"));
+    }
+}
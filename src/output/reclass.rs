@@ -0,0 +1,190 @@
+use std::fmt::{self, Write};
+
+use super::{ClassField, Formatter, SchemaMap, slugify};
+
+/// Writes the schema classes as the `Data.xml` of a ReClass.NET project, with a node per field.
+///
+/// ReClass.NET nodes don't have an offset, so the space between fields is filled with hex nodes.
+/// Fields of a type without a matching node are written as hex nodes that are named after the
+/// field and commented with its type.
+pub fn write_reclass(schemas: &SchemaMap, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(
+        fmt,
+        "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>"
+    )?;
+    writeln!(fmt, "<reclass version=\"65537\" type=\"x64\">")?;
+
+    fmt.indent(|fmt| {
+        writeln!(fmt, "<classes>")?;
+
+        fmt.indent(|fmt| {
+            for (module_name, (classes, _)) in schemas {
+                for class in classes {
+                    writeln!(
+                        fmt,
+                        "<class name=\"{}\" comment=\"{}\" address=\"0\">",
+                        xml_escape(&slugify(&class.name)),
+                        xml_escape(module_name)
+                    )?;
+
+                    fmt.indent(|fmt| {
+                        let mut fields: Vec<_> = class.fields.iter().collect();
+
+                        fields.sort_by_key(|field| field.offset);
+
+                        let mut cursor = 0;
+
+                        for (i, field) in fields.iter().enumerate() {
+                            // Fields that overlap the previous one can't be represented.
+                            if field.offset < cursor || field.offset >= class.size {
+                                continue;
+                            }
+
+                            write_padding(fmt, field.offset - cursor)?;
+
+                            let end = fields[i + 1..]
+                                .iter()
+                                .map(|field| field.offset)
+                                .find(|offset| *offset > field.offset)
+                                .unwrap_or(class.size)
+                                .min(class.size);
+
+                            cursor = write_field(fmt, field, end - field.offset)? + field.offset;
+                        }
+
+                        write_padding(fmt, class.size - cursor)
+                    })?;
+
+                    writeln!(fmt, "</class>")?;
+                }
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt, "</classes>")
+    })?;
+
+    writeln!(fmt, "</reclass>")
+}
+
+/// Writes the node of a field that has `space` bytes until the next field, and returns its size.
+fn write_field(fmt: &mut Formatter<'_>, field: &ClassField, space: i32) -> Result<i32, fmt::Error> {
+    let (node_type, size) = node_type(&field.type_name)
+        .filter(|(_, size)| *size <= space)
+        .unwrap_or_else(|| hex_node(space));
+
+    writeln!(
+        fmt,
+        "<node type=\"{}\" name=\"{}\" comment=\"{}\" hidden=\"false\" />",
+        node_type,
+        xml_escape(&field.name),
+        xml_escape(&field.type_name)
+    )?;
+
+    Ok(size)
+}
+
+fn write_padding(fmt: &mut Formatter<'_>, mut size: i32) -> fmt::Result {
+    while size > 0 {
+        let (node_type, node_size) = hex_node(size);
+
+        writeln!(
+            fmt,
+            "<node type=\"{}\" name=\"\" comment=\"\" hidden=\"false\" />",
+            node_type
+        )?;
+
+        size -= node_size;
+    }
+
+    Ok(())
+}
+
+/// Returns the largest hex node that fits into `size` bytes.
+fn hex_node(size: i32) -> (&'static str, i32) {
+    match size {
+        8.. => ("Hex64Node", 8),
+        4..=7 => ("Hex32Node", 4),
+        2..=3 => ("Hex16Node", 2),
+        _ => ("Hex8Node", 1),
+    }
+}
+
+/// Returns the ReClass.NET node type and size of a schema type.
+fn node_type(type_name: &str) -> Option<(&'static str, i32)> {
+    match type_name {
+        "bool" => Some(("BoolNode", 1)),
+        "char" | "uint8" => Some(("UInt8Node", 1)),
+        "int8" => Some(("Int8Node", 1)),
+        "int16" => Some(("Int16Node", 2)),
+        "uint16" => Some(("UInt16Node", 2)),
+        "int32" => Some(("Int32Node", 4)),
+        "uint32" => Some(("UInt32Node", 4)),
+        "int64" => Some(("Int64Node", 8)),
+        "uint64" => Some(("UInt64Node", 8)),
+        "float32" => Some(("FloatNode", 4)),
+        "float64" => Some(("DoubleNode", 8)),
+        "Vector2D" => Some(("Vector2Node", 8)),
+        "Vector" | "QAngle" => Some(("Vector3Node", 12)),
+        "Vector4D" => Some(("Vector4Node", 16)),
+        _ => None,
+    }
+}
+
+fn xml_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::Class;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn reclass_padding() {
+        let field = |name: &str, type_name: &str, offset| ClassField {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            offset,
+            alignment: None,
+        };
+
+        let class = Class {
+            name: "C_Test".to_string(),
+            module_name: "client.dll".to_string(),
+            parent_name: None,
+            size: 0x18,
+            alignment: 8,
+            metadata: Vec::new(),
+            fields: vec![
+                field("m_iHealth", "int32", 0x4),
+                field("m_hOwner", "CHandle<C_BaseEntity>", 0x8),
+                field("m_vecOrigin", "Vector", 0xC),
+            ],
+        };
+
+        let schemas = SchemaMap::from([("client.dll".to_string(), (vec![class], Vec::new()))]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(0), ValueFormat::Hex);
+
+        write_reclass(&schemas, &mut fmt).unwrap();
+
+        let node_types: Vec<_> = out
+            .lines()
+            .filter_map(|line| line.strip_prefix("<node type=\""))
+            .map(|line| &line[..line.find('"').unwrap()])
+            .collect();
+
+        assert_eq!(
+            node_types,
+            ["Hex32Node", "Int32Node", "Hex32Node", "Vector3Node"]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::{Formatter, Item};
+
+/// Writes an item in a custom file type.
+pub trait FileWriter {
+    fn write(&self, fmt: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// Creates the [`FileWriter`] of an item.
+pub type WriterFactory =
+    Box<dyn for<'i> Fn(&'i Item<'i>) -> Box<dyn FileWriter + 'i> + Send + Sync>;
+
+/// The custom file types that are generated in addition to the configured ones.
+///
+/// A registered file type takes precedence over a built-in one of the same name, but not over a
+/// custom template.
+#[derive(Default)]
+pub struct OutputRegistry {
+    factories: BTreeMap<String, WriterFactory>,
+}
+
+impl OutputRegistry {
+    /// Registers the writer factory of a file type, replacing any previous one.
+    pub fn register(&mut self, file_type: &str, factory: WriterFactory) {
+        self.factories.insert(file_type.to_string(), factory);
+    }
+
+    pub fn get(&self, file_type: &str) -> Option<&WriterFactory> {
+        self.factories.get(file_type)
+    }
+
+    pub fn file_types(&self) -> impl Iterator<Item = &str> {
+        self.factories.keys().map(|file_type| file_type.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt::Write;
+
+    use super::*;
+
+    use crate::output::{IndentStyle, ValueFormat};
+
+    struct Names<'a>(&'a Item<'a>);
+
+    impl FileWriter for Names<'_> {
+        fn write(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+            if let Item::Offsets(offsets) = self.0 {
+                for (module_name, offsets) in offsets.iter() {
+                    for name in offsets.keys() {
+                        writeln!(fmt, "{}::{}", module_name, name)?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn custom_file_type() {
+        let mut registry = OutputRegistry::default();
+
+        registry.register("txt", Box::new(|item| Box::new(Names(item))));
+
+        let offsets = BTreeMap::from([(
+            "client.dll".to_string(),
+            BTreeMap::from([("dwEntityList".to_string(), 0x10)]),
+        )]);
+
+        let item = Item::Offsets(&offsets);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        registry.get("txt").unwrap()(&item).write(&mut fmt).unwrap();
+
+        assert_eq!(out, "client.dll::dwEntityList\n");
+        assert_eq!(registry.file_types().collect::<Vec<_>>(), ["txt"]);
+        assert!(registry.get("rs").is_none());
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{self, Write};
+
+use super::Formatter;
+
+/// The name of the generated crate.
+const CRATE_NAME: &str = "cs2-offsets";
+
+/// A generated Rust file that is part of the crate.
+pub struct CrateFile {
+    /// The category of the file, which is its first module below the root namespace.
+    pub category: &'static str,
+
+    /// The name of the file without its extension (e.g. `offsets` or `client_dll`).
+    pub stem: String,
+
+    /// The name of the file as it's written to the output directory.
+    pub file_name: String,
+}
+
+/// Writes the manifest of the crate, whose major version is the build number of the game since any
+/// update can move the offsets.
+pub fn write_cargo_toml(build_number: Option<u32>, fmt: &mut Formatter<'_>) -> fmt::Result {
+    writeln!(fmt, "[package]")?;
+    writeln!(fmt, "name = \"{}\"", CRATE_NAME)?;
+    writeln!(fmt, "version = \"{}.0.0\"", build_number.unwrap_or(0))?;
+    writeln!(fmt, "edition = \"2021\"\n")?;
+
+    writeln!(fmt, "[lib]")?;
+    writeln!(fmt, "path = \"lib.rs\"\n")?;
+
+    writeln!(fmt, "[features]")?;
+    writeln!(fmt, "no_std = []")
+}
+
+/// Writes the root module of the crate, which includes each file as a private module and
+/// re-exports its category, so that the crate has the same paths as the files below their root
+/// namespace (e.g. `cs2_offsets::offsets::client_dll::dwEntityList`).
+pub fn write_lib_rs(
+    files: &[CrateFile],
+    root_namespace: &str,
+    fmt: &mut Formatter<'_>,
+) -> fmt::Result {
+    writeln!(fmt, "#![cfg_attr(feature = \"no_std\", no_std)]\n")?;
+
+    // The file modules can't have the names of the categories they export.
+    for file in files {
+        writeln!(fmt, "#[path = \"{}\"]", file.file_name)?;
+        writeln!(fmt, "mod {}_file;", file.stem)?;
+    }
+
+    writeln!(fmt)?;
+
+    // The schema files all have the same category, so their modules are merged.
+    let (schemas, others): (Vec<_>, Vec<_>) =
+        files.iter().partition(|file| file.category == "schemas");
+
+    for file in others {
+        writeln!(
+            fmt,
+            "pub use {}_file::{}::{};",
+            file.stem, root_namespace, file.category
+        )?;
+    }
+
+    if !schemas.is_empty() {
+        writeln!(fmt)?;
+
+        fmt.block("pub mod schemas", false, |fmt| {
+            for file in schemas {
+                writeln!(
+                    fmt,
+                    "pub use crate::{}_file::{}::schemas::*;",
+                    file.stem, root_namespace
+                )?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn lib_rs_exports() {
+        let file = |category, stem: &str| CrateFile {
+            category,
+            stem: stem.to_string(),
+            file_name: format!("{}_14041.rs", stem),
+        };
+
+        let files = [
+            file("offsets", "offsets"),
+            file("schemas", "client_dll"),
+            file("schemas", "engine2_dll"),
+        ];
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        write_lib_rs(&files, "cs2_dumper", &mut fmt).unwrap();
+
+        assert!(out.contains("#[path = \"offsets_14041.rs\"]\nmod offsets_file;\n"));
+        assert!(out.contains("pub use offsets_file::cs2_dumper::offsets;\n"));
+        assert!(out.contains(
+            "pub mod schemas {\n    pub use crate::client_dll_file::cs2_dumper::schemas::*;\n    pub use crate::engine2_dll_file::cs2_dumper::schemas::*;\n}"
+        ));
+    }
+}
@@ -3,15 +3,28 @@ use std::fmt::{self, Write};
 
 use heck::{AsPascalCase, AsSnakeCase};
 
-use serde_json::json;
+use serde_json::{Value, json};
 
-use super::{CodeWriter, Formatter, SchemaMap, slugify, zig_ident};
+use super::{
+    CodeOptions, CodeWriter, Formatter, JAVA_PACKAGE, SchemaMap, cpp_ident, cs_ident, go_ident,
+    java_ident, lua_key, py_ident, rs_ident, signed_hex, slugify, toml_key, toml_string, ts_ident,
+    zig_ident,
+};
 
-use crate::analysis::ClassMetadata;
+use crate::analysis::{Class, ClassField, ClassMetadata};
 
 impl CodeWriter for SchemaMap {
-    fn write_cs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        fmt.block("namespace CS2Dumper.Schemas", false, |fmt| {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        if opts.cs_explicit_layout {
+            writeln!(fmt, "using System.Runtime.InteropServices;\n")?;
+        }
+
+        let namespace = format!(
+            "namespace {}.Schemas",
+            opts.root_namespace("CS2Dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
             for (module_name, (classes, enums)) in self {
                 writeln!(fmt, "// Module: {}", module_name)?;
                 writeln!(fmt, "// Class count: {}", classes.len())?;
@@ -34,7 +47,11 @@ impl CodeWriter for SchemaMap {
                             writeln!(fmt, "// Member count: {}", enum_.size)?;
 
                             fmt.block(
-                                &format!("public enum {} : {}", slugify(&enum_.name), type_name),
+                                &format!(
+                                    "public enum {} : {}",
+                                    cs_ident(&slugify(&enum_.name)),
+                                    type_name
+                                ),
                                 false,
                                 |fmt| {
                                     let members = enum_
@@ -51,7 +68,11 @@ impl CodeWriter for SchemaMap {
                                                     )
                                                 };
 
-                                            format!("{} = {}", member.name, formatted_value)
+                                            format!(
+                                                "{} = {}",
+                                                cs_ident(&member.name),
+                                                formatted_value
+                                            )
                                         })
                                         .collect::<Vec<_>>()
                                         .join(",\n");
@@ -70,18 +91,30 @@ impl CodeWriter for SchemaMap {
 
                             writeln!(fmt, "// Parent: {}", parent_name)?;
                             writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                            writeln!(fmt, "// Size: {:#X}", class.size)?;
+                            writeln!(fmt, "// Alignment: {}", class.alignment)?;
+
+                            write_metadata(fmt, "//", &class.metadata)?;
+
+                            let union_groups = class.union_groups();
 
-                            write_metadata(fmt, &class.metadata)?;
+                            if opts.cs_explicit_layout {
+                                write_cs_struct(fmt, class, &union_groups)?;
+
+                                continue;
+                            }
 
                             fmt.block(
-                                &format!("public static class {}", slugify(&class.name)),
+                                &format!("public static class {}", cs_ident(&slugify(&class.name))),
                                 false,
                                 |fmt| {
                                     for field in &class.fields {
                                         writeln!(
                                             fmt,
-                                            "public const nint {} = {:#X}; // {}",
-                                            field.name, field.offset, field.type_name
+                                            "public const nint {} = {}; // {}",
+                                            cs_ident(&field.name),
+                                            fmt.value(field.offset),
+                                            field_comment(field, &union_groups)
                                         )?;
                                     }
 
@@ -99,12 +132,96 @@ impl CodeWriter for SchemaMap {
         })
     }
 
-    fn write_hpp(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_go(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+
+        for (module_name, (classes, enums)) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+            writeln!(fmt, "// Class count: {}", classes.len())?;
+            writeln!(fmt, "// Enum count: {}", enums.len())?;
+
+            let prefix = AsPascalCase(slugify(module_name)).to_string();
+
+            for enum_ in enums {
+                let type_name = match enum_.alignment {
+                    1 => "uint8",
+                    2 => "uint16",
+                    4 => "uint32",
+                    8 => "uint64",
+                    _ => continue,
+                };
+
+                writeln!(fmt, "// Alignment: {}", enum_.alignment)?;
+                writeln!(fmt, "// Member count: {}", enum_.size)?;
+
+                let enum_name = format!("{}_{}", prefix, slugify(&enum_.name));
+
+                writeln!(fmt, "type {} {}\n", enum_name, type_name)?;
+                writeln!(fmt, "const (")?;
+
+                fmt.indent(|fmt| {
+                    for member in &enum_.members {
+                        writeln!(
+                            fmt,
+                            "{}_{} {} = {}",
+                            enum_name,
+                            member.name,
+                            enum_name,
+                            format_unsigned_enum_member_value(member.value, type_name)
+                        )?;
+                    }
+
+                    Ok(())
+                })?;
+
+                writeln!(fmt, ")")?;
+            }
+
+            for class in classes {
+                let parent_name = class
+                    .parent_name
+                    .as_deref()
+                    .map(slugify)
+                    .unwrap_or("None".to_string());
+
+                writeln!(fmt, "// Parent: {}", parent_name)?;
+                writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                writeln!(fmt, "// Size: {:#X}", class.size)?;
+                writeln!(fmt, "// Alignment: {}", class.alignment)?;
+
+                write_metadata(fmt, "//", &class.metadata)?;
+
+                fmt.block(
+                    &format!("type {}_{} struct", prefix, slugify(&class.name)),
+                    false,
+                    |fmt| {
+                        for field in &class.fields {
+                            writeln!(
+                                fmt,
+                                "{} uintptr `offset:\"{}\"` // {}",
+                                go_ident(&field.name),
+                                fmt.value(field.offset),
+                                field.type_name
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(fmt, "#pragma once\n")?;
         writeln!(fmt, "#include <cstddef>")?;
         writeln!(fmt, "#include <cstdint>\n")?;
 
-        fmt.block("namespace cs2_dumper", false, |fmt| {
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
             fmt.block("namespace schemas", false, |fmt| {
                 for (module_name, (classes, enums)) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -128,7 +245,11 @@ impl CodeWriter for SchemaMap {
                                 writeln!(fmt, "// Member count: {}", enum_.size)?;
 
                                 fmt.block(
-                                    &format!("enum class {} : {}", slugify(&enum_.name), type_name),
+                                    &format!(
+                                        "enum class {} : {}",
+                                        cpp_ident(&slugify(&enum_.name)),
+                                        type_name
+                                    ),
                                     true,
                                     |fmt| {
                                         let members = enum_
@@ -151,7 +272,11 @@ impl CodeWriter for SchemaMap {
                                                     format!("{:#X}", max_value)
                                                 };
 
-                                                format!("{} = {}", member.name, formatted_value)
+                                                format!(
+                                                    "{} = {}",
+                                                    cpp_ident(&member.name),
+                                                    formatted_value
+                                                )
                                             })
                                             .collect::<Vec<_>>()
                                             .join(",\n");
@@ -170,18 +295,24 @@ impl CodeWriter for SchemaMap {
 
                                 writeln!(fmt, "// Parent: {}", parent_name)?;
                                 writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                                writeln!(fmt, "// Size: {:#X}", class.size)?;
+                                writeln!(fmt, "// Alignment: {}", class.alignment)?;
 
-                                write_metadata(fmt, &class.metadata)?;
+                                write_metadata(fmt, "//", &class.metadata)?;
+
+                                let union_groups = class.union_groups();
 
                                 fmt.block(
-                                    &format!("namespace {}", slugify(&class.name)),
+                                    &format!("namespace {}", cpp_ident(&slugify(&class.name))),
                                     false,
                                     |fmt| {
                                         for field in &class.fields {
                                             writeln!(
                                                 fmt,
-                                                "constexpr std::ptrdiff_t {} = {:#X}; // {}",
-                                                field.name, field.offset, field.type_name
+                                                "constexpr std::ptrdiff_t {} = {}; // {}",
+                                                cpp_ident(&field.name),
+                                                fmt.value(field.offset),
+                                                field_comment(field, &union_groups)
                                             )?;
                                         }
 
@@ -200,98 +331,311 @@ impl CodeWriter for SchemaMap {
         })
     }
 
-    fn write_json(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        let content: BTreeMap<_, _> = self
-            .iter()
-            .map(|(module_name, (classes, enums))| {
-                let classes: BTreeMap<_, _> = classes
-                    .iter()
-                    .map(|class| {
-                        let fields: BTreeMap<_, _> = class
-                            .fields
-                            .iter()
-                            .map(|field| (&field.name, field.offset))
-                            .collect();
-
-                        let metadata: Vec<_> = class
-                            .metadata
-                            .iter()
-                            .map(|metadata| match metadata {
-                                ClassMetadata::NetworkChangeCallback { name } => json!({
-                                    "type": "NetworkChangeCallback",
-                                    "name": name,
-                                }),
-                                ClassMetadata::NetworkVarNames { name, type_name } => json!({
-                                    "type": "NetworkVarNames",
-                                    "name": name,
-                                    "type_name": type_name,
-                                }),
-                                ClassMetadata::Unknown { name } => json!({
-                                    "type": "Unknown",
-                                    "name": name,
-                                }),
-                            })
-                            .collect();
-
-                        (
-                            slugify(&class.name),
-                            json!({
-                                "parent": class.parent_name,
-                                "fields": fields,
-                                "metadata": metadata
-                            }),
-                        )
-                    })
-                    .collect();
-
-                let enums: BTreeMap<_, _> = enums
-                    .iter()
-                    .map(|enum_| {
-                        let members: BTreeMap<_, _> = enum_
-                            .members
-                            .iter()
-                            .map(|member| (&member.name, member.value))
-                            .collect();
-
-                        let type_name = match enum_.alignment {
-                            1 => "uint8",
-                            2 => "uint16",
-                            4 => "uint32",
-                            8 => "uint64",
-                            _ => "unknown",
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+
+        for (module_name, (classes, enums)) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+            writeln!(fmt, "// Class count: {}", classes.len())?;
+            writeln!(fmt, "// Enum count: {}", enums.len())?;
+
+            fmt.block(
+                &format!("public final class {}", slugify(module_name)),
+                false,
+                |fmt| {
+                    for enum_ in enums {
+                        let (type_name, suffix) = match enum_.alignment {
+                            1 | 2 | 4 => ("int", ""),
+                            8 => ("long", "L"),
+                            _ => continue,
                         };
 
-                        (
-                            slugify(&enum_.name),
-                            json!({
-                                "alignment": enum_.alignment,
-                                "type": type_name,
-                                "members": members,
-                            }),
-                        )
-                    })
-                    .collect();
-
-                (
-                    module_name,
-                    json!({
-                        "classes": classes,
-                        "enums": enums,
-                    }),
-                )
-            })
-            .collect();
+                        writeln!(fmt, "// Alignment: {}", enum_.alignment)?;
+                        writeln!(fmt, "// Member count: {}", enum_.size)?;
+
+                        let enum_name = java_ident(&slugify(&enum_.name));
+
+                        fmt.block(&format!("public enum {}", enum_name), false, |fmt| {
+                            let members = enum_
+                                .members
+                                .iter()
+                                .map(|member| {
+                                    let wrapped_value = format_unsigned_enum_member_value(
+                                        member.value,
+                                        if suffix.is_empty() { "u32" } else { "u64" },
+                                    );
+
+                                    format!(
+                                        "{}({}{})",
+                                        java_ident(&member.name),
+                                        wrapped_value,
+                                        suffix
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(",\n");
+
+                            writeln!(fmt, "{};\n", members)?;
+                            writeln!(fmt, "public final {} value;\n", type_name)?;
+
+                            fmt.block(
+                                &format!("{}({} value)", enum_name, type_name),
+                                false,
+                                |fmt| writeln!(fmt, "this.value = value;"),
+                            )
+                        })?;
+                    }
+
+                    for class in classes {
+                        let parent_name = class
+                            .parent_name
+                            .as_deref()
+                            .map(slugify)
+                            .unwrap_or("None".to_string());
+
+                        writeln!(fmt, "// Parent: {}", parent_name)?;
+                        writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                        writeln!(fmt, "// Size: {:#X}", class.size)?;
+                        writeln!(fmt, "// Alignment: {}", class.alignment)?;
+
+                        write_metadata(fmt, "//", &class.metadata)?;
+
+                        fmt.block(
+                            &format!(
+                                "public static final class {}",
+                                java_ident(&slugify(&class.name))
+                            ),
+                            false,
+                            |fmt| {
+                                for field in &class.fields {
+                                    writeln!(
+                                        fmt,
+                                        "public static final int {} = {}; // {}",
+                                        java_ident(&field.name),
+                                        fmt.value(field.offset),
+                                        field.type_name
+                                    )?;
+                                }
+
+                                Ok(())
+                            },
+                        )?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        let content = json_content(self);
 
         fmt.write_str(&serde_json::to_string_pretty(&content).unwrap())
     }
 
-    fn write_rs(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, (classes, enums)) in self {
+            for class in classes {
+                for field in &class.fields {
+                    fmt.write_jsonl_record(&json!({
+                        "module": module_name,
+                        "class": class.name,
+                        "name": field.name,
+                        "offset": field.offset,
+                        "type": field.type_name,
+                    }))?;
+                }
+            }
+
+            for enum_ in enums {
+                for member in &enum_.members {
+                    fmt.write_jsonl_record(&json!({
+                        "module": module_name,
+                        "enum": enum_.name,
+                        "name": member.name,
+                        "value": member.value,
+                    }))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "schemas = {{")?;
+
+            fmt.indent(|fmt| {
+                for (module_name, (classes, enums)) in self {
+                    writeln!(fmt, "-- Module: {}", module_name)?;
+                    writeln!(fmt, "-- Class count: {}", classes.len())?;
+                    writeln!(fmt, "-- Enum count: {}", enums.len())?;
+
+                    let module_name = lua_key(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    writeln!(fmt, "{} = {{", module_name)?;
+
+                    fmt.indent(|fmt| {
+                        for enum_ in enums {
+                            writeln!(fmt, "-- Alignment: {}", enum_.alignment)?;
+                            writeln!(fmt, "-- Member count: {}", enum_.size)?;
+                            writeln!(fmt, "{} = {{", lua_key(&slugify(&enum_.name)))?;
+
+                            fmt.indent(|fmt| {
+                                for member in &enum_.members {
+                                    writeln!(
+                                        fmt,
+                                        "{} = {},",
+                                        lua_key(&member.name),
+                                        signed_hex(member.value)
+                                    )?;
+                                }
+
+                                Ok(())
+                            })?;
+
+                            writeln!(fmt, "}},")?;
+                        }
+
+                        for class in classes {
+                            let parent_name = class
+                                .parent_name
+                                .as_deref()
+                                .map(slugify)
+                                .unwrap_or("None".to_string());
+
+                            writeln!(fmt, "-- Parent: {}", parent_name)?;
+                            writeln!(fmt, "-- Field count: {}", class.fields.len())?;
+                            writeln!(fmt, "-- Size: {:#X}", class.size)?;
+                            writeln!(fmt, "-- Alignment: {}", class.alignment)?;
+
+                            write_metadata(fmt, "--", &class.metadata)?;
+
+                            writeln!(fmt, "{} = {{", lua_key(&slugify(&class.name)))?;
+
+                            fmt.indent(|fmt| {
+                                for field in &class.fields {
+                                    writeln!(
+                                        fmt,
+                                        "{} = {}, -- {}",
+                                        lua_key(&field.name),
+                                        fmt.value(field.offset),
+                                        field.type_name
+                                    )?;
+                                }
+
+                                Ok(())
+                            })?;
+
+                            writeln!(fmt, "}},")?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "}},")?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "from dataclasses import dataclass")?;
+        writeln!(fmt, "from enum import IntEnum\n")?;
+
+        for (module_name, (classes, enums)) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "# Class count: {}", classes.len())?;
+            writeln!(fmt, "# Enum count: {}", enums.len())?;
+            writeln!(fmt, "class {}:", AsSnakeCase(slugify(module_name)))?;
+
+            fmt.indent(|fmt| {
+                for enum_ in enums {
+                    writeln!(fmt, "# Alignment: {}", enum_.alignment)?;
+                    writeln!(fmt, "# Member count: {}", enum_.size)?;
+                    writeln!(fmt, "class {}(IntEnum):", py_ident(&slugify(&enum_.name)))?;
+
+                    fmt.indent(|fmt| {
+                        if enum_.members.is_empty() {
+                            return writeln!(fmt, "pass");
+                        }
+
+                        for member in &enum_.members {
+                            writeln!(
+                                fmt,
+                                "{} = {}",
+                                py_ident(&member.name),
+                                signed_hex(member.value)
+                            )?;
+                        }
+
+                        Ok(())
+                    })?;
+                }
+
+                for class in classes {
+                    let parent_name = class
+                        .parent_name
+                        .as_deref()
+                        .map(slugify)
+                        .unwrap_or("None".to_string());
+
+                    writeln!(fmt, "# Parent: {}", parent_name)?;
+                    writeln!(fmt, "# Field count: {}", class.fields.len())?;
+                    writeln!(fmt, "# Size: {:#X}", class.size)?;
+                    writeln!(fmt, "# Alignment: {}", class.alignment)?;
+
+                    write_metadata(fmt, "#", &class.metadata)?;
+
+                    writeln!(fmt, "@dataclass(frozen=True)")?;
+                    writeln!(fmt, "class {}:", py_ident(&slugify(&class.name)))?;
+
+                    fmt.indent(|fmt| {
+                        if class.fields.is_empty() {
+                            return writeln!(fmt, "pass");
+                        }
+
+                        for field in &class.fields {
+                            writeln!(
+                                fmt,
+                                "{}: int = {}  # {}",
+                                py_ident(&field.name),
+                                fmt.value(field.offset),
+                                field.type_name
+                            )?;
+                        }
+
+                        Ok(())
+                    })?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
         writeln!(
             fmt,
             "#![allow(non_upper_case_globals, non_camel_case_types, non_snake_case, unused)]\n"
         )?;
 
-        fmt.block("pub mod cs2_dumper", false, |fmt| {
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
             fmt.block("pub mod schemas", false, |fmt| {
                 for (module_name, (classes, enums)) in self {
                     writeln!(fmt, "// Module: {}", module_name)?;
@@ -318,7 +662,7 @@ impl CodeWriter for SchemaMap {
                                     &format!(
                                         "#[repr({})]\npub enum {}",
                                         type_name,
-                                        slugify(&enum_.name),
+                                        rs_ident(&slugify(&enum_.name)),
                                     ),
                                     false,
                                     |fmt| {
@@ -341,7 +685,8 @@ impl CodeWriter for SchemaMap {
 
                                                 Some(format!(
                                                     "{} = {}",
-                                                    member.name, formatted_value
+                                                    rs_ident(&member.name),
+                                                    formatted_value
                                                 ))
                                             })
                                             .collect::<Vec<_>>()
@@ -361,18 +706,24 @@ impl CodeWriter for SchemaMap {
 
                                 writeln!(fmt, "// Parent: {}", parent_name)?;
                                 writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                                writeln!(fmt, "// Size: {:#X}", class.size)?;
+                                writeln!(fmt, "// Alignment: {}", class.alignment)?;
+
+                                write_metadata(fmt, "//", &class.metadata)?;
 
-                                write_metadata(fmt, &class.metadata)?;
+                                let union_groups = class.union_groups();
 
                                 fmt.block(
-                                    &format!("pub mod {}", slugify(&class.name)),
+                                    &format!("pub mod {}", rs_ident(&slugify(&class.name))),
                                     false,
                                     |fmt| {
                                         for field in &class.fields {
                                             writeln!(
                                                 fmt,
-                                                "pub const {}: usize = {:#X}; // {}",
-                                                field.name, field.offset, field.type_name
+                                                "pub const {}: usize = {}; // {}",
+                                                rs_ident(&field.name),
+                                                fmt.value(field.offset),
+                                                field_comment(field, &union_groups)
                                             )?;
                                         }
 
@@ -391,7 +742,220 @@ impl CodeWriter for SchemaMap {
         })
     }
 
-    fn write_zig(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, (classes, enums)) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "# Class count: {}", classes.len())?;
+            writeln!(fmt, "# Enum count: {}", enums.len())?;
+
+            let module_key = toml_key(module_name);
+
+            for class in classes {
+                let class_key =
+                    format!("{}.classes.{}", module_key, toml_key(&slugify(&class.name)));
+
+                writeln!(fmt, "[{}]", class_key)?;
+
+                if let Some(parent_name) = &class.parent_name {
+                    writeln!(fmt, "parent = {}", toml_string(parent_name))?;
+                }
+
+                writeln!(fmt, "size = {:#X}", class.size)?;
+                writeln!(fmt, "alignment = {}", class.alignment)?;
+
+                let metadata = class
+                    .metadata
+                    .iter()
+                    .map(|metadata| match metadata {
+                        ClassMetadata::NetworkChangeCallback { name } => format!(
+                            "{{ type = \"NetworkChangeCallback\", name = {} }}",
+                            toml_string(name)
+                        ),
+                        ClassMetadata::NetworkVarNames { name, type_name } => format!(
+                            "{{ type = \"NetworkVarNames\", name = {}, type_name = {} }}",
+                            toml_string(name),
+                            toml_string(type_name)
+                        ),
+                        ClassMetadata::Unknown { name } => {
+                            format!("{{ type = \"Unknown\", name = {} }}", toml_string(name))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                writeln!(fmt, "metadata = [{}]", metadata)?;
+                writeln!(fmt, "[{}.fields]", class_key)?;
+
+                for field in &class.fields {
+                    writeln!(
+                        fmt,
+                        "{} = {}",
+                        toml_key(&field.name),
+                        fmt.value(field.offset)
+                    )?;
+                }
+
+                writeln!(fmt, "[{}.field_alignments]", class_key)?;
+
+                for field in &class.fields {
+                    if let Some(alignment) = field.alignment {
+                        writeln!(fmt, "{} = {}", toml_key(&field.name), alignment)?;
+                    }
+                }
+            }
+
+            writeln!(fmt, "[{}.enums]", module_key)?;
+
+            for enum_ in enums {
+                let members = enum_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        format!("{} = {}", toml_key(&member.name), signed_hex(member.value))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let type_name = match enum_.alignment {
+                    1 => "uint8",
+                    2 => "uint16",
+                    4 => "uint32",
+                    8 => "uint64",
+                    _ => "unknown",
+                };
+
+                writeln!(
+                    fmt,
+                    "{} = {{ alignment = {}, type = \"{}\", members = {{ {} }} }}",
+                    toml_key(&slugify(&enum_.name)),
+                    enum_.alignment,
+                    type_name,
+                    members
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("export namespace schemas", false, |fmt| {
+                for (module_name, (classes, enums)) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+                    writeln!(fmt, "// Class count: {}", classes.len())?;
+                    writeln!(fmt, "// Enum count: {}", enums.len())?;
+
+                    fmt.block(
+                        &format!("export namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for enum_ in enums {
+                                writeln!(fmt, "// Alignment: {}", enum_.alignment)?;
+                                writeln!(fmt, "// Member count: {}", enum_.size)?;
+
+                                let enum_name = ts_ident(&slugify(&enum_.name));
+
+                                fmt.block(
+                                    &format!("export const enum {}", enum_name),
+                                    false,
+                                    |fmt| {
+                                        let members = enum_
+                                            .members
+                                            .iter()
+                                            .map(|member| {
+                                                format!(
+                                                    "{} = {}",
+                                                    member.name,
+                                                    signed_hex(member.value)
+                                                )
+                                            })
+                                            .collect::<Vec<_>>()
+                                            .join(",\n");
+
+                                        writeln!(fmt, "{}", members)
+                                    },
+                                )?;
+                            }
+
+                            for class in classes {
+                                let parent_name = class
+                                    .parent_name
+                                    .as_deref()
+                                    .map(slugify)
+                                    .unwrap_or("None".to_string());
+
+                                writeln!(fmt, "// Parent: {}", parent_name)?;
+                                writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                                writeln!(fmt, "// Size: {:#X}", class.size)?;
+                                writeln!(fmt, "// Alignment: {}", class.alignment)?;
+
+                                write_metadata(fmt, "//", &class.metadata)?;
+
+                                let class_name = ts_ident(&slugify(&class.name));
+
+                                fmt.block(
+                                    &format!("export interface {}", class_name),
+                                    false,
+                                    |fmt| {
+                                        for field in &class.fields {
+                                            writeln!(
+                                                fmt,
+                                                "/** Offset: {} */",
+                                                fmt.value(field.offset)
+                                            )?;
+
+                                            writeln!(
+                                                fmt,
+                                                "{}: number; // {}",
+                                                field.name, field.type_name
+                                            )?;
+                                        }
+
+                                        Ok(())
+                                    },
+                                )?;
+
+                                fmt.block(
+                                    &format!("export const {}: {} =", class_name, class_name),
+                                    true,
+                                    |fmt| {
+                                        for field in &class.fields {
+                                            writeln!(
+                                                fmt,
+                                                "{}: {},",
+                                                field.name,
+                                                fmt.value(field.offset)
+                                            )?;
+                                        }
+
+                                        Ok(())
+                                    },
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        let content = json_content(self);
+
+        fmt.write_str(&serde_yaml::to_string(&content).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
         fmt.block("pub const cs2_dumper = struct", true, |fmt| {
             fmt.block("pub const schemas = struct", true, |fmt| {
                 for (module_name, (classes, enums)) in self {
@@ -434,10 +998,11 @@ impl CodeWriter for SchemaMap {
                                                     return None;
                                                 }
 
-                                                let formatted_value = format_zig_enum_member_value(
-                                                    member.value,
-                                                    type_name,
-                                                );
+                                                let formatted_value =
+                                                    format_unsigned_enum_member_value(
+                                                        member.value,
+                                                        type_name,
+                                                    );
 
                                                 Some(format!(
                                                     "{} = {}",
@@ -462,8 +1027,10 @@ impl CodeWriter for SchemaMap {
 
                                 writeln!(fmt, "// Parent: {}", parent_name)?;
                                 writeln!(fmt, "// Field count: {}", class.fields.len())?;
+                                writeln!(fmt, "// Size: {:#X}", class.size)?;
+                                writeln!(fmt, "// Alignment: {}", class.alignment)?;
 
-                                write_metadata(fmt, &class.metadata)?;
+                                write_metadata(fmt, "//", &class.metadata)?;
 
                                 let class_name = zig_ident(&slugify(&class.name));
 
@@ -474,9 +1041,9 @@ impl CodeWriter for SchemaMap {
                                         for field in &class.fields {
                                             writeln!(
                                                 fmt,
-                                                "pub const {}: usize = {:#X}; // {}",
+                                                "pub const {}: usize = {}; // {}",
                                                 zig_ident(&field.name),
-                                                field.offset,
+                                                fmt.value(field.offset),
                                                 field.type_name
                                             )?;
                                         }
@@ -497,24 +1064,249 @@ impl CodeWriter for SchemaMap {
     }
 }
 
-fn write_metadata(fmt: &mut Formatter<'_>, metadata: &[ClassMetadata]) -> fmt::Result {
+fn json_content(schemas: &SchemaMap) -> BTreeMap<&String, Value> {
+    schemas
+        .iter()
+        .map(|(module_name, (classes, enums))| {
+            let classes: BTreeMap<_, _> = classes
+                .iter()
+                .map(|class| {
+                    let fields: BTreeMap<_, _> = class
+                        .fields
+                        .iter()
+                        .map(|field| (&field.name, field.offset))
+                        .collect();
+
+                    let field_alignments: BTreeMap<_, _> = class
+                        .fields
+                        .iter()
+                        .filter_map(|field| Some((&field.name, field.alignment?)))
+                        .collect();
+
+                    let field_union_groups = class.union_groups();
+
+                    let field_array_counts: BTreeMap<_, _> = class
+                        .fields
+                        .iter()
+                        .filter_map(|field| Some((&field.name, field.array_type()?.1)))
+                        .collect();
+
+                    let field_type_params: BTreeMap<_, _> = class
+                        .fields
+                        .iter()
+                        .filter_map(|field| Some((&field.name, field.template_params()?.1)))
+                        .collect();
+
+                    let metadata: Vec<_> = class
+                        .metadata
+                        .iter()
+                        .map(|metadata| match metadata {
+                            ClassMetadata::NetworkChangeCallback { name } => json!({
+                                "type": "NetworkChangeCallback",
+                                "name": name,
+                            }),
+                            ClassMetadata::NetworkVarNames { name, type_name } => json!({
+                                "type": "NetworkVarNames",
+                                "name": name,
+                                "type_name": type_name,
+                            }),
+                            ClassMetadata::Unknown { name } => json!({
+                                "type": "Unknown",
+                                "name": name,
+                            }),
+                        })
+                        .collect();
+
+                    (
+                        slugify(&class.name),
+                        json!({
+                            "parent": class.parent_name,
+                            "size": class.size,
+                            "alignment": class.alignment,
+                            "fields": fields,
+                            "field_alignments": field_alignments,
+                            "field_union_groups": field_union_groups,
+                            "field_type_params": field_type_params,
+                            "field_array_counts": field_array_counts,
+                            "metadata": metadata
+                        }),
+                    )
+                })
+                .collect();
+
+            let enums: BTreeMap<_, _> = enums
+                .iter()
+                .map(|enum_| {
+                    let members: BTreeMap<_, _> = enum_
+                        .members
+                        .iter()
+                        .map(|member| (&member.name, member.value))
+                        .collect();
+
+                    let type_name = match enum_.alignment {
+                        1 => "uint8",
+                        2 => "uint16",
+                        4 => "uint32",
+                        8 => "uint64",
+                        _ => "unknown",
+                    };
+
+                    (
+                        slugify(&enum_.name),
+                        json!({
+                            "alignment": enum_.alignment,
+                            "type": type_name,
+                            "members": members,
+                        }),
+                    )
+                })
+                .collect();
+
+            (
+                module_name,
+                json!({
+                    "classes": classes,
+                    "enums": enums,
+                }),
+            )
+        })
+        .collect()
+}
+
+/// Returns the comment of a field with its type, and its union group if it shares its offset with
+/// other fields.
+fn field_comment(field: &ClassField, union_groups: &BTreeMap<&str, usize>) -> String {
+    match union_groups.get(field.name.as_str()) {
+        Some(group) => format!("{} (union {})", field.type_name, group),
+        None => field.type_name.clone(),
+    }
+}
+
+/// Writes a class as a C# struct with an explicit layout of its size.
+///
+/// Fields of primitive types get the matching C# type. All other fields are byte buffers that span
+/// up to the next field, and the gaps between fields, like the ones of the members of the parent
+/// classes, are padding buffers.
+fn write_cs_struct(
+    fmt: &mut Formatter<'_>,
+    class: &Class,
+    union_groups: &BTreeMap<&str, usize>,
+) -> fmt::Result {
+    let mut fields: Vec<_> = class.fields.iter().collect();
+
+    fields.sort_by_key(|field| field.offset);
+
+    writeln!(
+        fmt,
+        "[StructLayout(LayoutKind.Explicit, Size = {:#X})]",
+        class.size
+    )?;
+
+    fmt.block(
+        &format!("public unsafe struct {}", cs_ident(&slugify(&class.name))),
+        false,
+        |fmt| {
+            let mut end = 0;
+
+            for (i, field) in fields.iter().enumerate() {
+                if field.offset > end {
+                    write_cs_padding(fmt, end, field.offset - end)?;
+                }
+
+                // The space up to the next field, since the size of a field isn't known.
+                let next_offset = fields[i + 1..]
+                    .iter()
+                    .map(|field| field.offset)
+                    .find(|&offset| offset > field.offset)
+                    .unwrap_or(class.size);
+
+                let space = next_offset - field.offset;
+
+                match cs_primitive_type(&field.type_name) {
+                    Some((type_name, size)) if size <= space => {
+                        writeln!(
+                            fmt,
+                            "[FieldOffset({:#X})] public {} {}; // {}",
+                            field.offset,
+                            type_name,
+                            cs_ident(&field.name),
+                            field_comment(field, union_groups)
+                        )?;
+
+                        end = end.max(field.offset + size);
+                    }
+                    _ => {
+                        writeln!(
+                            fmt,
+                            "[FieldOffset({:#X})] public fixed byte {}[{:#X}]; // {}",
+                            field.offset,
+                            cs_ident(&field.name),
+                            space.max(1),
+                            field_comment(field, union_groups)
+                        )?;
+
+                        end = end.max(field.offset + space.max(1));
+                    }
+                }
+            }
+
+            if class.size > end {
+                write_cs_padding(fmt, end, class.size - end)?;
+            }
+
+            Ok(())
+        },
+    )
+}
+
+fn write_cs_padding(fmt: &mut Formatter<'_>, offset: i32, size: i32) -> fmt::Result {
+    writeln!(
+        fmt,
+        "[FieldOffset({:#X})] public fixed byte _pad{:X}[{:#X}];",
+        offset, offset, size
+    )
+}
+
+/// Returns the C# type and size of a primitive schema type.
+fn cs_primitive_type(type_name: &str) -> Option<(&'static str, i32)> {
+    match type_name {
+        "bool" => Some(("bool", 1)),
+        "int8" => Some(("sbyte", 1)),
+        "uint8" | "char" => Some(("byte", 1)),
+        "int16" => Some(("short", 2)),
+        "uint16" => Some(("ushort", 2)),
+        "int32" => Some(("int", 4)),
+        "uint32" => Some(("uint", 4)),
+        "float32" => Some(("float", 4)),
+        "int64" => Some(("long", 8)),
+        "uint64" => Some(("ulong", 8)),
+        "float64" => Some(("double", 8)),
+        _ => None,
+    }
+}
+
+fn write_metadata(
+    fmt: &mut Formatter<'_>,
+    comment: &str,
+    metadata: &[ClassMetadata],
+) -> fmt::Result {
     if metadata.is_empty() {
         return Ok(());
     }
 
-    writeln!(fmt, "//")?;
-    writeln!(fmt, "// Metadata:")?;
+    writeln!(fmt, "{}", comment)?;
+    writeln!(fmt, "{} Metadata:", comment)?;
 
     for metadata in metadata {
         match metadata {
             ClassMetadata::NetworkChangeCallback { name } => {
-                writeln!(fmt, "// NetworkChangeCallback: {}", name)?;
+                writeln!(fmt, "{} NetworkChangeCallback: {}", comment, name)?;
             }
             ClassMetadata::NetworkVarNames { name, type_name } => {
-                writeln!(fmt, "// NetworkVarNames: {} ({})", name, type_name)?;
+                writeln!(fmt, "{} NetworkVarNames: {} ({})", comment, name, type_name)?;
             }
             ClassMetadata::Unknown { name } => {
-                writeln!(fmt, "// {}", name)?;
+                writeln!(fmt, "{} {}", comment, name)?;
             }
         }
     }
@@ -522,18 +1314,79 @@ fn write_metadata(fmt: &mut Formatter<'_>, metadata: &[ClassMetadata]) -> fmt::R
     Ok(())
 }
 
-fn format_zig_enum_member_value(value: i64, type_name: &str) -> String {
+fn format_unsigned_enum_member_value(value: i64, type_name: &str) -> String {
     if value >= 0 {
         return format!("{:#X}", value);
     }
 
     let wrapped_value = match type_name {
-        "u8" => value as u8 as u64,
-        "u16" => value as u16 as u64,
-        "u32" => value as u32 as u64,
-        "u64" => value as u64,
+        "u8" | "uint8" => value as u8 as u64,
+        "u16" | "uint16" => value as u16 as u64,
+        "u32" | "uint32" => value as u32 as u64,
+        "u64" | "uint64" => value as u64,
         _ => 0,
     };
 
     format!("{:#X}", wrapped_value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::{IndentStyle, ValueFormat};
+
+    #[test]
+    fn cs_explicit_layout() {
+        let field = |name: &str, type_name: &str, offset| ClassField {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            offset,
+            alignment: None,
+        };
+
+        let schemas = SchemaMap::from([(
+            "client.dll".to_string(),
+            (
+                vec![Class {
+                    name: "C_BaseEntity".to_string(),
+                    module_name: "client.dll".to_string(),
+                    parent_name: None,
+                    size: 0x40,
+                    alignment: 8,
+                    metadata: Vec::new(),
+                    fields: vec![
+                        field("m_hOwnerEntity", "CHandle<C_BaseEntity>", 0x20),
+                        field("m_iHealth", "int32", 0x10),
+                        field("m_flSpeed", "float32", 0x24),
+                    ],
+                }],
+                Vec::new(),
+            ),
+        )]);
+
+        let mut out = String::new();
+        let mut fmt = Formatter::new(&mut out, IndentStyle::Spaces(4), ValueFormat::Hex);
+
+        let opts = CodeOptions {
+            cs_explicit_layout: true,
+            ..Default::default()
+        };
+
+        schemas.write_cs(&mut fmt, &opts).unwrap();
+
+        assert!(out.starts_with("using System.Runtime.InteropServices;\n"));
+
+        for line in [
+            "[StructLayout(LayoutKind.Explicit, Size = 0x40)]",
+            "public unsafe struct C_BaseEntity {",
+            "[FieldOffset(0x0)] public fixed byte _pad0[0x10];",
+            "[FieldOffset(0x10)] public int m_iHealth; // int32",
+            "[FieldOffset(0x14)] public fixed byte _pad14[0xC];",
+            "[FieldOffset(0x20)] public fixed byte m_hOwnerEntity[0x4]; // CHandle<C_BaseEntity>",
+            "[FieldOffset(0x24)] public float m_flSpeed; // float32",
+            "[FieldOffset(0x28)] public fixed byte _pad28[0x18];",
+        ] {
+            assert!(out.contains(line), "{}", out);
+        }
+    }
+}
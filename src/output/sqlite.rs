@@ -0,0 +1,162 @@
+use std::path::Path;
+
+use anyhow::Result;
+
+use chrono::{DateTime, Utc};
+
+use rusqlite::{Connection, params};
+
+use super::AnalysisResult;
+
+const CREATE_TABLES: &str = "
+CREATE TABLE IF NOT EXISTS meta (
+    build_number INTEGER NOT NULL,
+    key TEXT NOT NULL,
+    value TEXT NOT NULL,
+    PRIMARY KEY (build_number, key)
+);
+
+CREATE TABLE IF NOT EXISTS offsets (
+    build_number INTEGER NOT NULL,
+    module TEXT NOT NULL,
+    name TEXT NOT NULL,
+    value INTEGER NOT NULL,
+    PRIMARY KEY (build_number, module, name)
+);
+
+CREATE TABLE IF NOT EXISTS interfaces (
+    build_number INTEGER NOT NULL,
+    module TEXT NOT NULL,
+    name TEXT NOT NULL,
+    value INTEGER NOT NULL,
+    PRIMARY KEY (build_number, module, name)
+);
+
+CREATE TABLE IF NOT EXISTS schemas (
+    build_number INTEGER NOT NULL,
+    module TEXT NOT NULL,
+    class TEXT NOT NULL,
+    field TEXT NOT NULL,
+    \"offset\" INTEGER NOT NULL,
+    type TEXT NOT NULL,
+    PRIMARY KEY (build_number, module, class, field)
+);
+";
+
+/// Adds the offsets, interfaces and schemas of the categories in `categories` for `build_number`
+/// to the SQLite database at `path`, which is created if it doesn't exist.
+///
+/// The rows of other builds are kept, so a database that is written to after every update holds
+/// the history of every entry.
+pub fn to_sqlite(
+    path: &Path,
+    result: &AnalysisResult,
+    categories: &[String],
+    build_number: u32,
+    timestamp: DateTime<Utc>,
+) -> Result<()> {
+    let mut conn = Connection::open(path)?;
+
+    insert(&mut conn, result, categories, build_number, timestamp)
+}
+
+fn insert(
+    conn: &mut Connection,
+    result: &AnalysisResult,
+    categories: &[String],
+    build_number: u32,
+    timestamp: DateTime<Utc>,
+) -> Result<()> {
+    conn.execute_batch(CREATE_TABLES)?;
+
+    let is_enabled = |category: &str| categories.iter().any(|c| c == category);
+
+    let tx = conn.transaction()?;
+
+    tx.execute(
+        "INSERT OR REPLACE INTO meta VALUES (?1, 'timestamp', ?2)",
+        params![build_number, timestamp.to_rfc3339()],
+    )?;
+
+    for (table, enabled, modules) in [
+        ("offsets", is_enabled("offsets"), &result.offsets),
+        ("interfaces", is_enabled("interfaces"), &result.interfaces),
+    ] {
+        if !enabled {
+            continue;
+        }
+
+        let mut stmt = tx.prepare(&format!(
+            "INSERT OR REPLACE INTO {} VALUES (?1, ?2, ?3, ?4)",
+            table
+        ))?;
+
+        for (module_name, entries) in modules {
+            for (name, value) in entries {
+                stmt.execute(params![build_number, module_name, name, *value as i64])?;
+            }
+        }
+    }
+
+    if is_enabled("schemas") {
+        let mut stmt =
+            tx.prepare("INSERT OR REPLACE INTO schemas VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+
+        for (module_name, (classes, _)) in &result.schemas {
+            for class in classes {
+                for field in &class.fields {
+                    stmt.execute(params![
+                        build_number,
+                        module_name,
+                        class.name,
+                        field.name,
+                        field.offset,
+                        field.type_name
+                    ])?;
+                }
+            }
+        }
+    }
+
+    tx.commit()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn sqlite_history() {
+        let result = |value| AnalysisResult {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([("dwEntityList".to_string(), value)]),
+            )]),
+            ..Default::default()
+        };
+
+        let categories = ["offsets".to_string()];
+
+        let mut conn = Connection::open_in_memory().unwrap();
+
+        insert(&mut conn, &result(0x10), &categories, 14079, Utc::now()).unwrap();
+        insert(&mut conn, &result(0x20), &categories, 14080, Utc::now()).unwrap();
+        insert(&mut conn, &result(0x30), &categories, 14080, Utc::now()).unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT build_number, value FROM offsets ORDER BY build_number")
+            .unwrap();
+
+        let rows: Vec<(u32, i64)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(rows, [(14079, 0x10), (14080, 0x30)]);
+    }
+}
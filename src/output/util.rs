@@ -0,0 +1,58 @@
+use heck::{AsPascalCase, AsShoutySnakeCase};
+
+use super::formatter::CaseConvention;
+
+/// Returns `input` with every character that isn't alphanumeric replaced by an underscore, so
+/// that it can be used as an identifier or a file name.
+///
+/// This is how module names become the names of their schema files and namespaces (e.g.
+/// `client.dll` becomes `client_dll`). The length of the name is kept and nothing is prefixed,
+/// so a name that starts with a digit still does. Non-ASCII letters and digits are kept as well.
+#[inline]
+pub fn slugify(input: &str) -> String {
+    slugify_with_case(input, CaseConvention::Preserve)
+}
+
+/// Returns the [`slugify`]d `input` in the given case convention.
+pub(crate) fn slugify_with_case(input: &str, case_convention: CaseConvention) -> String {
+    let slug = input.replace(|c: char| !c.is_alphanumeric(), "_");
+
+    match case_convention {
+        CaseConvention::Preserve => slug,
+        CaseConvention::Pascal => AsPascalCase(slug).to_string(),
+        CaseConvention::ScreamingSnake => AsShoutySnakeCase(slug).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_names() {
+        // Module names with and without an extension.
+        assert_eq!(slugify("client.dll"), "client_dll");
+        assert_eq!(slugify("libclient.so"), "libclient_so");
+        assert_eq!(slugify("client"), "client");
+
+        // Valid identifiers are kept as they are.
+        assert_eq!(slugify("dwEntityList"), "dwEntityList");
+        assert_eq!(slugify("m_iHealth"), "m_iHealth");
+
+        // Leading digits aren't prefixed.
+        assert_eq!(slugify("3DSkyboxParams"), "3DSkyboxParams");
+
+        // Every character of a name without any alphanumeric ones is replaced.
+        assert_eq!(slugify("::<>"), "____");
+        assert_eq!(slugify(""), "");
+
+        assert_eq!(
+            slugify_with_case("client.dll", CaseConvention::Pascal),
+            "ClientDll"
+        );
+        assert_eq!(
+            slugify_with_case("dwEntityList", CaseConvention::ScreamingSnake),
+            "DW_ENTITY_LIST"
+        );
+    }
+}
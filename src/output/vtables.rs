@@ -0,0 +1,413 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use heck::{AsPascalCase, AsSnakeCase};
+
+use serde_json::json;
+
+use super::{
+    CodeOptions, CodeWriter, Formatter, JAVA_PACKAGE, VtableMap, go_ident, java_ident, lua_key,
+    py_ident, slugify, toml_key, ts_ident, zig_ident,
+};
+
+impl CodeWriter for VtableMap {
+    fn write_cs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "namespace {}.Vtables",
+            opts.root_namespace("CS2Dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            for (module_name, vtables) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static class {}", AsPascalCase(slugify(module_name))),
+                    false,
+                    |fmt| {
+                        for (name, entries) in vtables {
+                            writeln!(fmt, "// Entry count: {}", entries.len())?;
+
+                            fmt.block(&format!("public struct {}", name), false, |fmt| {
+                                for entry in entries {
+                                    writeln!(
+                                        fmt,
+                                        "public const nint Fn{} = {:#X};",
+                                        entry.index, entry.address
+                                    )?;
+                                }
+
+                                Ok(())
+                            })?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_go(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package cs2\n")?;
+
+        for (module_name, vtables) in self {
+            writeln!(fmt, "// Module: {}", module_name)?;
+
+            let prefix = AsPascalCase(slugify(module_name)).to_string();
+
+            for (name, entries) in vtables {
+                writeln!(fmt, "// Entry count: {}", entries.len())?;
+
+                fmt.block(
+                    &format!("var {}_{}_Vtable = [...]uintptr", prefix, go_ident(name)),
+                    false,
+                    |fmt| {
+                        for entry in entries {
+                            writeln!(fmt, "{:#X},", entry.address)?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_hpp(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "#pragma once\n")?;
+        writeln!(fmt, "#include <cstddef>")?;
+        writeln!(fmt, "#include <cstdint>\n")?;
+
+        let namespace = format!("namespace {}", opts.root_namespace("cs2_dumper", "::"));
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("namespace vtables", false, |fmt| {
+                for (module_name, vtables) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, entries) in vtables {
+                                writeln!(fmt, "// Entry count: {}", entries.len())?;
+
+                                fmt.block(&format!("struct {}", name), true, |fmt| {
+                                    for entry in entries {
+                                        writeln!(
+                                            fmt,
+                                            "void* fn_{}; // {:#X}",
+                                            entry.index, entry.address
+                                        )?;
+                                    }
+
+                                    Ok(())
+                                })?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_java(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "package {};\n", opts.root_namespace(JAVA_PACKAGE, "."))?;
+
+        fmt.block("public final class vtables", false, |fmt| {
+            for (module_name, vtables) in self {
+                writeln!(fmt, "// Module: {}", module_name)?;
+
+                fmt.block(
+                    &format!("public static final class {}", slugify(module_name)),
+                    false,
+                    |fmt| {
+                        for (name, entries) in vtables {
+                            writeln!(fmt, "// Entry count: {}", entries.len())?;
+
+                            fmt.block(
+                                &format!("public static final long[] {} =", java_ident(name)),
+                                true,
+                                |fmt| {
+                                    for entry in entries {
+                                        writeln!(fmt, "{:#X}L,", entry.address)?;
+                                    }
+
+                                    Ok(())
+                                },
+                            )?;
+                        }
+
+                        Ok(())
+                    },
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn write_json(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        let content: BTreeMap<_, _> = self
+            .iter()
+            .map(|(module_name, vtables)| {
+                let vtables: BTreeMap<_, _> = vtables
+                    .iter()
+                    .map(|(name, entries)| (name, json!({ "vtable": entries })))
+                    .collect();
+
+                (module_name, vtables)
+            })
+            .collect();
+
+        fmt.write_str(&serde_json::to_string_pretty(&content).unwrap())
+    }
+
+    fn write_jsonl(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, vtables) in self {
+            for (name, entries) in vtables {
+                for entry in entries {
+                    fmt.write_jsonl_record(&json!({
+                        "module": module_name,
+                        "name": name,
+                        "index": entry.index,
+                        "address": entry.address,
+                    }))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_lua(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "local cs2_dumper = {{")?;
+
+        fmt.indent(|fmt| {
+            writeln!(fmt, "vtables = {{")?;
+
+            fmt.indent(|fmt| {
+                for (module_name, vtables) in self {
+                    writeln!(fmt, "-- Module: {}", module_name)?;
+
+                    let module_name = lua_key(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    writeln!(fmt, "{} = {{", module_name)?;
+
+                    fmt.indent(|fmt| {
+                        for (name, entries) in vtables {
+                            // Lua arrays are 1-indexed, so entry 0 is at index 1.
+                            writeln!(fmt, "-- Entry count: {}", entries.len())?;
+                            writeln!(fmt, "{} = {{", lua_key(name))?;
+
+                            fmt.indent(|fmt| {
+                                for entry in entries {
+                                    writeln!(fmt, "{:#X},", entry.address)?;
+                                }
+
+                                Ok(())
+                            })?;
+
+                            writeln!(fmt, "}},")?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "}},")?;
+                }
+
+                Ok(())
+            })?;
+
+            writeln!(fmt, "}},")
+        })?;
+
+        writeln!(fmt, "}}\n")?;
+        writeln!(fmt, "return cs2_dumper")
+    }
+
+    fn write_py(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, vtables) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "class {}:", AsSnakeCase(slugify(module_name)))?;
+
+            fmt.indent(|fmt| {
+                if vtables.is_empty() {
+                    return writeln!(fmt, "pass");
+                }
+
+                for (name, entries) in vtables {
+                    writeln!(fmt, "# Entry count: {}", entries.len())?;
+                    writeln!(fmt, "{} = [", py_ident(name))?;
+
+                    fmt.indent(|fmt| {
+                        for entry in entries {
+                            writeln!(fmt, "{:#X},", entry.address)?;
+                        }
+
+                        Ok(())
+                    })?;
+
+                    writeln!(fmt, "]")?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        writeln!(fmt, "#![allow(non_upper_case_globals, unused)]\n")?;
+
+        opts.root_modules(fmt, "cs2_dumper", |fmt| {
+            fmt.block("pub mod vtables", false, |fmt| {
+                for (module_name, vtables) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("pub mod {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, entries) in vtables {
+                                writeln!(
+                                    fmt,
+                                    "pub const {}: [usize; {}] = [",
+                                    name,
+                                    entries.len()
+                                )?;
+
+                                fmt.indent(|fmt| {
+                                    for entry in entries {
+                                        writeln!(fmt, "{:#X},", entry.address)?;
+                                    }
+
+                                    Ok(())
+                                })?;
+
+                                writeln!(fmt, "];")?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_toml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        for (module_name, vtables) in self {
+            writeln!(fmt, "# Module: {}", module_name)?;
+            writeln!(fmt, "[{}]", toml_key(module_name))?;
+
+            for (name, entries) in vtables {
+                let entries = entries
+                    .iter()
+                    .map(|entry| format!("{:#X}", entry.address))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                writeln!(fmt, "{} = [{}]", toml_key(name), entries)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_ts(&self, fmt: &mut Formatter<'_>, opts: &CodeOptions<'_>) -> fmt::Result {
+        let namespace = format!(
+            "export namespace {}",
+            opts.root_namespace("cs2_dumper", ".")
+        );
+
+        fmt.block(&namespace, false, |fmt| {
+            fmt.block("export namespace vtables", false, |fmt| {
+                for (module_name, vtables) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    fmt.block(
+                        &format!("export namespace {}", AsSnakeCase(slugify(module_name))),
+                        false,
+                        |fmt| {
+                            for (name, entries) in vtables {
+                                writeln!(
+                                    fmt,
+                                    "export const {}: readonly number[] = [",
+                                    ts_ident(name)
+                                )?;
+
+                                fmt.indent(|fmt| {
+                                    for entry in entries {
+                                        writeln!(fmt, "{:#X},", entry.address)?;
+                                    }
+
+                                    Ok(())
+                                })?;
+
+                                writeln!(fmt, "];")?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    fn write_yaml(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.write_str(&serde_yaml::to_string(self).unwrap())
+    }
+
+    fn write_zig(&self, fmt: &mut Formatter<'_>, _opts: &CodeOptions<'_>) -> fmt::Result {
+        fmt.block("pub const cs2_dumper = struct", true, |fmt| {
+            fmt.block("pub const vtables = struct", true, |fmt| {
+                for (module_name, vtables) in self {
+                    writeln!(fmt, "// Module: {}", module_name)?;
+
+                    let module_name = zig_ident(&AsSnakeCase(slugify(module_name)).to_string());
+
+                    fmt.block(
+                        &format!("pub const {} = struct", module_name),
+                        true,
+                        |fmt| {
+                            for (name, entries) in vtables {
+                                fmt.block(
+                                    &format!("pub const {} = [_]usize", zig_ident(name)),
+                                    true,
+                                    |fmt| {
+                                        for entry in entries {
+                                            writeln!(fmt, "{:#X},", entry.address)?;
+                                        }
+
+                                        Ok(())
+                                    },
+                                )?;
+                            }
+
+                            Ok(())
+                        },
+                    )?;
+                }
+
+                Ok(())
+            })
+        })
+    }
+}
@@ -0,0 +1,42 @@
+use anyhow::Result;
+
+use memflow::prelude::v1::*;
+
+use serde_json::{Value, json};
+
+use super::{AddressMode, OffsetMap};
+
+/// Returns an x64dbg database for each module that labels its offsets, which can be imported into
+/// a debugging session.
+///
+/// x64dbg expects the addresses of labels relative to their module, so absolute addresses are
+/// converted back using the module base addresses of the running game.
+pub fn databases<'a, P: Process + MemoryView>(
+    process: &mut P,
+    offsets: &'a OffsetMap,
+    address_mode: AddressMode,
+) -> Result<Vec<(&'a str, Value)>> {
+    offsets
+        .iter()
+        .map(|(module_name, offsets)| -> Result<_> {
+            let base = match address_mode {
+                AddressMode::Absolute => process.module_by_name(module_name)?.base.to_umem(),
+                AddressMode::Rva => 0,
+            };
+
+            let labels: Vec<_> = offsets
+                .iter()
+                .map(|(name, value)| {
+                    json!({
+                        "module": module_name,
+                        "address": format!("{:#X}", value - base),
+                        "manual": true,
+                        "text": name,
+                    })
+                })
+                .collect();
+
+            Ok((module_name.as_str(), json!({ "labels": labels })))
+        })
+        .collect()
+}
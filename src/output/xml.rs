@@ -0,0 +1,237 @@
+use anyhow::Result;
+
+use chrono::{DateTime, Utc};
+
+use memflow::prelude::v1::*;
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, Event};
+
+use super::{AnalysisResult, IndentStyle};
+
+/// Returns the buttons, interfaces, offsets and schemas of the categories in `categories` as a
+/// single XML document.
+pub fn to_xml(
+    result: &AnalysisResult,
+    categories: &[String],
+    build_number: Option<u32>,
+    timestamp: DateTime<Utc>,
+    indent_style: IndentStyle,
+) -> Result<String> {
+    let (indent_char, indent_size) = match indent_style {
+        IndentStyle::Spaces(size) => (b' ', size),
+        IndentStyle::Tabs => (b'\t', 1),
+    };
+
+    let mut writer = Writer::new_with_indent(Vec::new(), indent_char, indent_size);
+
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("utf-8"), None)))?;
+
+    let mut root = BytesStart::new("Dump");
+
+    root.push_attribute(("timestamp", timestamp.to_rfc3339().as_str()));
+
+    if let Some(build_number) = build_number {
+        root.push_attribute(("buildNumber", build_number.to_string().as_str()));
+    }
+
+    writer.write_event(Event::Start(root))?;
+
+    let is_enabled = |category: &str| categories.iter().any(|c| c == category);
+
+    if is_enabled("offsets") {
+        writer.write_event(Event::Start(BytesStart::new("Offsets")))?;
+
+        for (module_name, offsets) in &result.offsets {
+            for (name, value) in offsets {
+                write_value(&mut writer, "Offset", Some(module_name), name, *value)?;
+            }
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Offsets")))?;
+    }
+
+    if is_enabled("interfaces") {
+        writer.write_event(Event::Start(BytesStart::new("Interfaces")))?;
+
+        for (module_name, ifaces) in &result.interfaces {
+            for (name, value) in ifaces {
+                write_value(&mut writer, "Interface", Some(module_name), name, *value)?;
+            }
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Interfaces")))?;
+    }
+
+    if is_enabled("schemas") {
+        writer.write_event(Event::Start(BytesStart::new("Schemas")))?;
+
+        for (module_name, (classes, enums)) in &result.schemas {
+            writer.write_event(Event::Start(
+                BytesStart::new("Module").with_attributes([("name", module_name.as_str())]),
+            ))?;
+
+            for class in classes {
+                let mut start = BytesStart::new("Class");
+
+                start.push_attribute(("name", class.name.as_str()));
+
+                if let Some(parent_name) = &class.parent_name {
+                    start.push_attribute(("parent", parent_name.as_str()));
+                }
+
+                start.push_attribute(("size", format!("{:#X}", class.size).as_str()));
+
+                writer.write_event(Event::Start(start))?;
+
+                for field in &class.fields {
+                    let offset = format!("{:#X}", field.offset);
+
+                    let element = BytesStart::new("Field").with_attributes([
+                        ("name", field.name.as_str()),
+                        ("offset", offset.as_str()),
+                        ("type", field.type_name.as_str()),
+                    ]);
+
+                    writer.write_event(Event::Empty(element))?;
+                }
+
+                writer.write_event(Event::End(BytesEnd::new("Class")))?;
+            }
+
+            for enum_ in enums {
+                writer.write_event(Event::Start(BytesStart::new("Enum").with_attributes([
+                    ("name", enum_.name.as_str()),
+                    ("alignment", enum_.alignment.to_string().as_str()),
+                ])))?;
+
+                for member in &enum_.members {
+                    let value = member.value.to_string();
+
+                    let element = BytesStart::new("Member").with_attributes([
+                        ("name", member.name.as_str()),
+                        ("value", value.as_str()),
+                    ]);
+
+                    writer.write_event(Event::Empty(element))?;
+                }
+
+                writer.write_event(Event::End(BytesEnd::new("Enum")))?;
+            }
+
+            writer.write_event(Event::End(BytesEnd::new("Module")))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Schemas")))?;
+    }
+
+    if is_enabled("buttons") {
+        writer.write_event(Event::Start(BytesStart::new("Buttons")))?;
+
+        for (name, value) in &result.buttons {
+            write_value(&mut writer, "Button", None, name, *value)?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("Buttons")))?;
+    }
+
+    writer.write_event(Event::End(BytesEnd::new("Dump")))?;
+
+    Ok(String::from_utf8(writer.into_inner())?)
+}
+
+fn write_value(
+    writer: &mut Writer<Vec<u8>>,
+    tag: &str,
+    module_name: Option<&str>,
+    name: &str,
+    value: umem,
+) -> Result<()> {
+    let mut element = BytesStart::new(tag);
+
+    if let Some(module_name) = module_name {
+        element.push_attribute(("module", module_name));
+    }
+
+    element.push_attribute(("name", name));
+    element.push_attribute(("value", format!("{:#X}", value).as_str()));
+
+    writer.write_event(Event::Empty(element))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use quick_xml::Reader;
+
+    use super::*;
+
+    #[test]
+    fn xml_round_trip() {
+        let result = AnalysisResult {
+            buttons: BTreeMap::from([("attack".to_string(), 0x1A2B)]),
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([("dwEntityList".to_string(), 0x24CED50)]),
+            )]),
+            ..Default::default()
+        };
+
+        let categories = ["buttons".to_string(), "offsets".to_string()];
+
+        let xml = to_xml(
+            &result,
+            &categories,
+            Some(14080),
+            Utc::now(),
+            IndentStyle::Spaces(4),
+        )
+        .unwrap();
+
+        let mut reader = Reader::from_str(&xml);
+        let mut values = Vec::new();
+
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Empty(element) => {
+                    let attribute = |name: &str| {
+                        element
+                            .try_get_attribute(name)
+                            .unwrap()
+                            .map(|attribute| attribute.unescape_value().unwrap().into_owned())
+                    };
+
+                    values.push((
+                        String::from_utf8(element.name().as_ref().to_vec()).unwrap(),
+                        attribute("module"),
+                        attribute("name").unwrap(),
+                        attribute("value").unwrap(),
+                    ));
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        assert_eq!(
+            values,
+            [
+                (
+                    "Offset".to_string(),
+                    Some("client.dll".to_string()),
+                    "dwEntityList".to_string(),
+                    "0x24CED50".to_string()
+                ),
+                (
+                    "Button".to_string(),
+                    None,
+                    "attack".to_string(),
+                    "0x1A2B".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+
+use chrono::{DateTime, Utc};
+
+use log::{debug, info, warn};
+
+use prometheus::{Gauge, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+
+use serde_json::{Map, Value, json};
+
+use tiny_http::{Header, Method, Response, Server};
+
+use tungstenite::handshake::server::{ErrorResponse, Request as WsRequest, Response as WsResponse};
+use tungstenite::http::StatusCode;
+use tungstenite::{Error as WsError, Message, WebSocket};
+
+use crate::analysis::{AnalysisResult, CATEGORIES, DumpDiff};
+use crate::output;
+
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// The path that WebSocket clients connect to for updates.
+const UPDATES_PATH: &str = "/updates";
+
+/// How long a WebSocket client can take to complete the handshake or accept a message before it's
+/// dropped.
+const WS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often each WebSocket connection checks for updates to send between reading frames.
+const WS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The content type of the Prometheus text exposition format.
+const METRICS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Serves the JSON content of `result` on `addr` until the process exits, together with
+/// Prometheus metrics about the dump that took `duration`.
+pub fn serve(
+    addr: &str,
+    result: &AnalysisResult,
+    build_number: Option<u32>,
+    duration: Duration,
+) -> Result<()> {
+    let mut routes = BTreeMap::new();
+    let mut dump = Map::new();
+
+    for category in CATEGORIES {
+        let content = output::category_json(result, category)?;
+
+        routes.insert(
+            format!("/{}", category),
+            (JSON_CONTENT_TYPE, content.to_string()),
+        );
+
+        dump.insert(category.to_string(), content);
+    }
+
+    let timestamp = Utc::now();
+
+    let info = json!({
+        "timestamp": timestamp.to_rfc3339(),
+        "build_number": build_number,
+        "address_mode": result.address_mode,
+        "modules": output::module_stats(result),
+    });
+
+    routes.insert("/info".to_string(), (JSON_CONTENT_TYPE, info.to_string()));
+    dump.insert("info".to_string(), info);
+
+    routes.insert(
+        "/dump".to_string(),
+        (JSON_CONTENT_TYPE, Value::Object(dump).to_string()),
+    );
+
+    // The dump doesn't change while it's served, so neither do the metrics.
+    routes.insert(
+        "/metrics".to_string(),
+        (
+            METRICS_CONTENT_TYPE,
+            metrics(result, build_number, timestamp, duration)?,
+        ),
+    );
+
+    let server = Server::http(addr).map_err(|err| anyhow!("failed to bind {}: {}", addr, err))?;
+
+    info!("serving dump on http://{}", addr);
+
+    for request in server.incoming_requests() {
+        // Ignore the query string, if any.
+        let path = request.url().split('?').next().unwrap_or_default();
+
+        let response = match (request.method(), routes.get(path)) {
+            (Method::Get, Some((content_type, body))) => Response::from_string(body.as_str())
+                .with_header(Header::from_bytes("Content-Type", *content_type).unwrap()),
+            (Method::Get, None) => Response::from_string("not found").with_status_code(404),
+            _ => Response::from_string("method not allowed").with_status_code(405),
+        };
+
+        if let Err(err) = request.respond(response) {
+            warn!("failed to send response: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
+/// Pushes a message to each WebSocket client connected to `/updates` whenever the dump changes.
+pub struct UpdateServer {
+    clients: Arc<Mutex<Vec<Sender<String>>>>,
+}
+
+impl UpdateServer {
+    /// Accepts WebSocket connections on `addr` in the background until the process exits.
+    ///
+    /// Each connection is handled by its own thread, so that a slow client can't hold up the
+    /// others or the dump.
+    pub fn bind(addr: &str) -> Result<Self> {
+        let listener =
+            TcpListener::bind(addr).map_err(|err| anyhow!("failed to bind {}: {}", addr, err))?;
+
+        let clients = Arc::new(Mutex::new(Vec::new()));
+        let accepted = Arc::clone(&clients);
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let clients = Arc::clone(&accepted);
+
+                thread::spawn(move || {
+                    let Some(socket) = accept_client(stream) else {
+                        return;
+                    };
+
+                    let (sender, receiver) = mpsc::channel();
+
+                    clients.lock().unwrap().push(sender);
+
+                    serve_client(socket, &receiver);
+                });
+            }
+        });
+
+        info!("pushing updates on ws://{}{}", addr, UPDATES_PATH);
+
+        Ok(Self { clients })
+    }
+
+    /// Queues the changes of a re-dump for all clients, and drops the ones that disconnected.
+    pub fn push_update(&self, build_number: Option<u32>, diff: &DumpDiff) {
+        let message = update_message(build_number, diff).to_string();
+
+        self.clients
+            .lock()
+            .unwrap()
+            .retain(|client| client.send(message.clone()).is_ok());
+    }
+}
+
+/// Performs the WebSocket handshake of a connection to `/updates`, or returns `None` if it fails
+/// or takes too long.
+fn accept_client(stream: TcpStream) -> Option<WebSocket<TcpStream>> {
+    let callback = |request: &WsRequest, response: WsResponse| {
+        if request.uri().path() == UPDATES_PATH {
+            return Ok(response);
+        }
+
+        let mut response = ErrorResponse::new(Some("not found".to_string()));
+
+        *response.status_mut() = StatusCode::NOT_FOUND;
+
+        Err(response)
+    };
+
+    if let Err(err) = stream
+        .set_read_timeout(Some(WS_TIMEOUT))
+        .and_then(|()| stream.set_write_timeout(Some(WS_TIMEOUT)))
+    {
+        warn!("failed to set websocket timeouts: {}", err);
+
+        return None;
+    }
+
+    let socket = match tungstenite::accept_hdr(stream, callback) {
+        Ok(socket) => socket,
+        Err(err) => {
+            warn!("failed to accept websocket connection: {}", err);
+
+            return None;
+        }
+    };
+
+    // The reads only wait for the next poll, while the writes keep the timeout.
+    if let Err(err) = socket.get_ref().set_read_timeout(Some(WS_POLL_INTERVAL)) {
+        warn!("failed to set websocket timeouts: {}", err);
+
+        return None;
+    }
+
+    Some(socket)
+}
+
+/// Sends the queued updates to a client and reads its frames until it disconnects, fails or
+/// doesn't accept a message within the timeout.
+fn serve_client(mut socket: WebSocket<TcpStream>, updates: &Receiver<String>) {
+    loop {
+        let result = match updates.try_recv() {
+            Ok(message) => socket.send(Message::text(message)),
+            Err(TryRecvError::Disconnected) => return,
+            // Pings are answered and closes acknowledged when the socket is flushed.
+            Err(TryRecvError::Empty) => match socket.read() {
+                Ok(_) => socket.flush(),
+                Err(WsError::Io(err)) if is_timeout(&err) => socket.flush(),
+                Err(err) => Err(err),
+            },
+        };
+
+        if let Err(err) = result {
+            debug!("dropping websocket client: {}", err);
+
+            return;
+        }
+    }
+}
+
+#[inline]
+fn is_timeout(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    )
+}
+
+/// Returns the message that is pushed to WebSocket clients after a re-dump, with an entry for
+/// each offset that was added, removed or changed.
+fn update_message(build_number: Option<u32>, diff: &DumpDiff) -> Value {
+    let changed_offsets: Vec<_> = diff
+        .offsets
+        .iter()
+        .flat_map(|(module_name, changes)| {
+            changes.iter().map(move |(name, change)| {
+                // The kind and the old and new values of the change.
+                let mut entry = json!(change);
+
+                entry["module"] = json!(module_name);
+                entry["name"] = json!(name);
+
+                entry
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "dump_updated",
+        "build_number": build_number,
+        "changed_offsets": changed_offsets,
+    })
+}
+
+/// Returns the metrics of a dump in the Prometheus text format.
+fn metrics(
+    result: &AnalysisResult,
+    build_number: Option<u32>,
+    timestamp: DateTime<Utc>,
+    duration: Duration,
+) -> Result<String> {
+    let registry = Registry::new();
+
+    let timestamp_seconds = Gauge::new(
+        "cs2_dump_timestamp_seconds",
+        "The Unix time of the last dump.",
+    )?;
+
+    timestamp_seconds.set(timestamp.timestamp_millis() as f64 / 1000.0);
+    registry.register(Box::new(timestamp_seconds))?;
+
+    // There is no meaningful value if the build number couldn't be read.
+    if let Some(build_number) = build_number {
+        let build_number_gauge =
+            IntGauge::new("cs2_dump_build_number", "The build number of the game.")?;
+
+        build_number_gauge.set(build_number as i64);
+        registry.register(Box::new(build_number_gauge))?;
+    }
+
+    let offset_count = IntGaugeVec::new(
+        Opts::new("cs2_offset_count", "The number of offsets per module."),
+        &["module"],
+    )?;
+
+    for (module_name, offsets) in &result.offsets {
+        offset_count
+            .with_label_values(&[module_name.as_str()])
+            .set(offsets.len() as i64);
+    }
+
+    registry.register(Box::new(offset_count))?;
+
+    let schema_class_count = IntGauge::new(
+        "cs2_schema_class_count",
+        "The number of schema classes of all modules.",
+    )?;
+
+    schema_class_count.set(
+        result
+            .schemas
+            .values()
+            .map(|(classes, _)| classes.len() as i64)
+            .sum(),
+    );
+
+    registry.register(Box::new(schema_class_count))?;
+
+    let duration_seconds = Gauge::new(
+        "cs2_dump_duration_seconds",
+        "The duration of the last dump in seconds.",
+    )?;
+
+    duration_seconds.set(duration.as_secs_f64());
+    registry.register(Box::new(duration_seconds))?;
+
+    Ok(TextEncoder::new().encode_to_string(&registry.gather())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::Change;
+
+    #[test]
+    fn updates() {
+        let diff = DumpDiff {
+            offsets: BTreeMap::from([(
+                "client.dll".to_string(),
+                BTreeMap::from([
+                    (
+                        "dwEntityList".to_string(),
+                        Change::Changed {
+                            old: 0x24CED50,
+                            new: 0x24CFD50,
+                        },
+                    ),
+                    (
+                        "dwViewMatrix".to_string(),
+                        Change::Removed { old: 0x232EAC0 },
+                    ),
+                ]),
+            )]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            update_message(Some(14041), &diff),
+            json!({
+                "type": "dump_updated",
+                "build_number": 14041,
+                "changed_offsets": [
+                    {
+                        "module": "client.dll",
+                        "name": "dwEntityList",
+                        "kind": "changed",
+                        "old": 0x24CED50,
+                        "new": 0x24CFD50
+                    },
+                    {
+                        "module": "client.dll",
+                        "name": "dwViewMatrix",
+                        "kind": "removed",
+                        "old": 0x232EAC0
+                    }
+                ]
+            })
+        );
+    }
+}
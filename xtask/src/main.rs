@@ -0,0 +1,41 @@
+//! Build steps that aren't part of `cargo build`, run with `cargo xtask <task>`.
+
+use std::env;
+use std::process::{Command, ExitCode};
+
+/// The directory to write the man page to if none is given.
+const DEFAULT_MAN_DIR: &str = "target/man";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("man") => man(args.get(1).map_or(DEFAULT_MAN_DIR, String::as_str)),
+        _ => {
+            eprintln!("usage: cargo xtask man [<out-dir>]");
+
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes `cs2-dumper.1` to `out_dir` with the `--man-page` option of the dumper, so that it's
+/// generated from the same argument definitions as the binary that is packaged with it.
+fn man(out_dir: &str) -> ExitCode {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let status = Command::new(cargo)
+        .args(["run", "--release", "--package", "cs2-dumper", "--"])
+        .args(["--man-page", "--output", out_dir])
+        .status();
+
+    match status {
+        Ok(status) if status.success() => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("failed to run cargo: {}", err);
+
+            ExitCode::FAILURE
+        }
+    }
+}